@@ -0,0 +1,175 @@
+//! Exporting and importing tables as CSV, TSV or using any other delimiter.
+//!
+//! Fields are quoted according to [RFC 4180](https://www.rfc-editor.org/rfc/rfc4180): fields
+//! containing the delimiter, quotes or newlines are surrounded by quotes and quotes inside of
+//! fields are doubled. Records are separated by `\r\n` when writing, both `\r\n` and `\n` are
+//! accepted when reading.
+
+use std::io;
+use crate::error::{TableError, TableErrorKind};
+use crate::{ParseTableRow, TableRow};
+
+/// Writes the rows to `writer`, with a header containing the
+/// [field names](crate::TableRow::get_fields) of the row type. Fields are separated by
+/// `delimiter`.
+///
+/// # Example
+/// ```rust
+/// # use simple_tables::macros::table_row;
+/// #[table_row]
+/// struct MyTableRow {
+///     id: u32,
+///     name: String
+/// }
+///
+/// let rows = vec![MyTableRow { id: 1, name: "Crosby, Stills & Nash".to_string() }];
+/// let mut csv: Vec<u8> = Vec::new();
+/// simple_tables::csv::write_delimited(&rows, &mut csv, ',').unwrap();
+/// assert_eq!("id,name\r\n1,\"Crosby, Stills & Nash\"\r\n", String::from_utf8(csv).unwrap());
+/// ```
+pub fn write_delimited<Row: TableRow, W: io::Write>(rows: &[Row], mut writer: W, delimiter: char) -> io::Result<()> {
+    write_record(&mut writer, &Row::get_fields(), delimiter)?;
+    for row in rows {
+        write_record(&mut writer, &row.get_field_str(), delimiter)?;
+    }
+    writer.flush()
+}
+
+/// Writes one record, followed by `\r\n`
+fn write_record<S: AsRef<str>, W: io::Write>(writer: &mut W, fields: &[S], delimiter: char) -> io::Result<()> {
+    let record: Vec<String> = fields.iter().map(|field| quote(field.as_ref(), delimiter)).collect();
+    write!(writer, "{}\r\n", record.join(delimiter.to_string().as_str()))
+}
+
+/// Quotes a field if it contains the delimiter, a quote or a newline
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Reads rows from `reader`, of which the fields are separated by `delimiter`. The first record
+/// should be a header containing the names of the columns, which are mapped to the
+/// [fields](crate::TableRow::get_fields) of the rows by name. Columns that are not a field of the
+/// rows are ignored.
+///
+/// # Errors
+/// - [`CouldNotRead`](TableErrorKind::CouldNotRead) if the reader fails or the input isn't valid
+///   UTF-8
+/// - [`InvalidCsv`](TableErrorKind::InvalidCsv) if the input isn't valid CSV, a field of the rows
+///   is missing from the header or a record has the wrong amount of fields
+/// - [`CouldNotParse`](TableErrorKind::CouldNotParse) if a field can't be parsed to its type
+pub fn read_delimited<Row: ParseTableRow, R: io::Read>(mut reader: R, delimiter: char) -> Result<Vec<Row>, TableError> {
+    let mut input = String::new();
+    if let Err(err) = reader.read_to_string(&mut input) {
+        return Err(TableError { kind: TableErrorKind::CouldNotRead, message: err.to_string() });
+    }
+    let mut records = parse_records(&input, delimiter)?.into_iter();
+
+    let header = match records.next() {
+        Some(header) => header,
+        None => return Err(invalid_csv(1, "The input has no header".to_string()))
+    };
+    // The index in the header of each field, `None` for optional fields that are missing
+    let optional_fields = Row::get_optional_fields();
+    let indices: Vec<Option<usize>> = Row::get_fields().into_iter().map(|field| {
+        match header.fields.iter().position(|column| column == field) {
+            Some(i) => Ok(Some(i)),
+            None if optional_fields.contains(&field) => Ok(None),
+            None => Err(invalid_csv(header.line, format!("The header has no column `{}`", field)))
+        }
+    }).collect::<Result<_, _>>()?;
+
+    records.enumerate().map(|(row, record)| {
+        if record.fields.len() != header.fields.len() {
+            return Err(invalid_csv(record.line, format!(
+                "Expected {} fields, but found {}", header.fields.len(), record.fields.len()
+            )));
+        }
+        let fields: Vec<&str> = indices.iter().map(|i| match i {
+            Some(i) => record.fields[*i].as_str(),
+            None => ""
+        }).collect();
+        Row::parse_fields(&fields).map_err(|(column, reason)| TableError {
+            message: format!("Could not parse column `{}` of row {} (line {}): {}", column, row, record.line, reason),
+            kind: TableErrorKind::CouldNotParse { line: record.line, row, column: column.to_string() },
+        })
+    }).collect()
+}
+
+fn invalid_csv(line: usize, message: String) -> TableError {
+    TableError { kind: TableErrorKind::InvalidCsv { line }, message: format!("Invalid CSV on line {}: {}", line, message) }
+}
+
+/// A record in the input
+struct Record {
+    /// The line the record starts on
+    line: usize,
+    fields: Vec<String>,
+}
+
+/// Splits the input up into records. Empty lines are skipped.
+fn parse_records(input: &str, delimiter: char) -> Result<Vec<Record>, TableError> {
+    let mut records: Vec<Record> = Vec::new();
+    let mut fields: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut in_quotes = false;
+    // Whether the current field was quoted and the closing quote has been read
+    let mut after_quote = false;
+
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    chars.next();
+                    field.push('"');
+                },
+                '"' => {
+                    in_quotes = false;
+                    after_quote = true;
+                },
+                '\n' => {
+                    line += 1;
+                    field.push(c);
+                },
+                _ => field.push(c)
+            }
+        } else if c == delimiter {
+            fields.push(std::mem::take(&mut field));
+            after_quote = false;
+        } else if c == '\n' || (c == '\r' && chars.peek() == Some(&'\n')) {
+            if c == '\r' {
+                chars.next();
+            }
+            if !(fields.is_empty() && field.is_empty() && !after_quote) {
+                fields.push(std::mem::take(&mut field));
+                records.push(Record { line: record_line, fields: std::mem::take(&mut fields) });
+            }
+            after_quote = false;
+            line += 1;
+            record_line = line;
+        } else if after_quote {
+            return Err(invalid_csv(line, format!("Unexpected character `{}` after a closing quote", c)));
+        } else if c == '"' {
+            if !field.is_empty() {
+                return Err(invalid_csv(line, "Unexpected quote in an unquoted field".to_string()));
+            }
+            in_quotes = true;
+        } else {
+            field.push(c);
+        }
+    }
+    if in_quotes {
+        return Err(invalid_csv(record_line, "Unterminated quoted field".to_string()));
+    }
+    if !(fields.is_empty() && field.is_empty() && !after_quote) {
+        fields.push(field);
+        records.push(Record { line: record_line, fields });
+    }
+    Ok(records)
+}
@@ -1,13 +1,30 @@
 use std::fmt::{Debug, Display, Formatter};
 
 pub enum TableErrorKind {
-    CouldNotRemove
+    CouldNotRemove,
+    /// There is no column with the given name
+    UnknownColumn,
+    /// The input could not be read
+    CouldNotRead,
+    /// The input is not valid CSV, or does not match the table's rows. `line` is the line in the
+    /// input where the problem was found.
+    InvalidCsv { line: usize },
+    /// A field could not be parsed to the type of its column. `row` is the index of the row (not
+    /// counting the header) and `line` the line in the input where the row starts.
+    CouldNotParse { line: usize, row: usize, column: String },
+    /// There already is a row with the same uid in the table
+    DuplicateUid,
 }
 
 impl Debug for TableErrorKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::CouldNotRemove => write!(f, "CouldNotRemove")
+            Self::CouldNotRemove => write!(f, "CouldNotRemove"),
+            Self::UnknownColumn => write!(f, "UnknownColumn"),
+            Self::CouldNotRead => write!(f, "CouldNotRead"),
+            Self::InvalidCsv { line } => write!(f, "InvalidCsv {{ line: {} }}", line),
+            Self::CouldNotParse { line, row, column } => write!(f, "CouldNotParse {{ line: {}, row: {}, column: {:?} }}", line, row, column),
+            Self::DuplicateUid => write!(f, "DuplicateUid"),
         }
     }
 }
@@ -0,0 +1,124 @@
+//! Exporting tables as HTML.
+
+use crate::TableRow;
+
+/// Options for the HTML generated by [`to_html`](crate::html::to_html)
+#[derive(Debug, Clone)]
+pub struct HtmlOptions {
+    /// The `id` attribute of the `<table>` element
+    pub id: Option<String>,
+    /// The `class` attribute of the `<table>` element
+    pub class: Option<String>,
+    /// Every `<th>` and `<td>` element gets a class made up of this prefix and the name of its
+    /// column. Characters that can't be used in a class name are replaced by `-`.
+    pub column_class_prefix: String,
+}
+
+impl HtmlOptions {
+    /// Creates options with no `id` or `class` and `column-` as the prefix for column classes
+    pub fn new() -> Self {
+        HtmlOptions { id: None, class: None, column_class_prefix: String::from("column-") }
+    }
+
+    /// Sets the `id` attribute of the table
+    pub fn id(mut self, id: &str) -> Self {
+        self.id = Some(id.to_string());
+        self
+    }
+
+    /// Sets the `class` attribute of the table
+    pub fn class(mut self, class: &str) -> Self {
+        self.class = Some(class.to_string());
+        self
+    }
+}
+
+impl Default for HtmlOptions {
+    fn default() -> Self {
+        HtmlOptions::new()
+    }
+}
+
+/// Renders the rows as an HTML table, with a header containing the
+/// [field names](crate::TableRow::get_fields) of the row type.
+///
+/// Special characters in cells are escaped and newlines are replaced by `<br>`.
+///
+/// # Example
+/// ```rust
+/// # use simple_tables::macros::table_row;
+/// use simple_tables::html::{to_html, HtmlOptions};
+///
+/// #[table_row]
+/// struct MyTableRow {
+///     id: u32,
+///     name: String
+/// }
+///
+/// let rows = vec![MyTableRow { id: 1, name: "Simon & Garfunkel".to_string() }];
+/// let html = to_html(&rows, &HtmlOptions::new().class("bands"));
+/// assert_eq!(html, r#"<table class="bands">
+///   <thead>
+///     <tr>
+///       <th class="column-id">id</th>
+///       <th class="column-name">name</th>
+///     </tr>
+///   </thead>
+///   <tbody>
+///     <tr>
+///       <td class="column-id">1</td>
+///       <td class="column-name">Simon &amp; Garfunkel</td>
+///     </tr>
+///   </tbody>
+/// </table>"#);
+/// ```
+pub fn to_html<Row: TableRow>(rows: &[Row], options: &HtmlOptions) -> String {
+    let column_classes: Vec<String> = Row::get_fields().iter().map(|field| {
+        format!("{}{}", options.column_class_prefix, class_name(field))
+    }).collect();
+    let html_row = |cells: Vec<String>, tag: &str| -> Vec<String> {
+        let mut lines = vec![String::from("    <tr>")];
+        cells.iter().zip(column_classes.iter()).for_each(|(cell, class)| {
+            lines.push(format!("      <{} class=\"{}\">{}</{}>", tag, escape(class), escape(cell).replace('\n', "<br>"), tag));
+        });
+        lines.push(String::from("    </tr>"));
+        lines
+    };
+
+    let mut attributes = String::new();
+    if let Some(class) = &options.class {
+        attributes.push_str(&format!(" class=\"{}\"", escape(class)));
+    }
+    if let Some(id) = &options.id {
+        attributes.push_str(&format!(" id=\"{}\"", escape(id)));
+    }
+    let mut lines: Vec<String> = vec![format!("<table{}>", attributes), String::from("  <thead>")];
+    lines.append(&mut html_row(Row::get_fields().iter().map(|field| field.to_string()).collect(), "th"));
+    lines.push(String::from("  </thead>"));
+    lines.push(String::from("  <tbody>"));
+    rows.iter().for_each(|row| {
+        lines.append(&mut html_row(row.get_field_str(), "td"));
+    });
+    lines.push(String::from("  </tbody>"));
+    lines.push(String::from("</table>"));
+    lines.join("\n")
+}
+
+/// Escapes the characters that have a special meaning in HTML
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    s.chars().for_each(|c| match c {
+        '&' => escaped.push_str("&amp;"),
+        '<' => escaped.push_str("&lt;"),
+        '>' => escaped.push_str("&gt;"),
+        '"' => escaped.push_str("&quot;"),
+        '\'' => escaped.push_str("&#39;"),
+        _ => escaped.push(c),
+    });
+    escaped
+}
+
+/// Replaces the characters of a field name that can't be used in a class name
+fn class_name(field: &str) -> String {
+    field.chars().map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '-' }).collect()
+}
@@ -0,0 +1,148 @@
+//! A hash index on the uid of the rows of an [`IdTable`](crate::IdTable).
+//!
+//! The index is used by tables created with `#[table(rows = MyTableRow, uid = "id", indexed)]`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::OnceLock;
+
+/// Maps the uid of every row to its position in the table, so rows can be looked up in constant
+/// time.
+///
+/// The index is built the first time it is used. Pushing, inserting and removing rows keep the
+/// index up to date. Only borrowing all rows mutably removes the index, so it is built again on
+/// the next lookup.
+///
+/// When the same uid occurs multiple times, the index points to the first row with this uid, just
+/// like [`IdTable::get_row`](crate::IdTable::get_row).
+pub struct UidIndex<Uid> {
+    positions: OnceLock<HashMap<Uid, usize>>,
+    /// A row that has been borrowed mutably, together with its uid at that time. Its uid might
+    /// have been changed since.
+    edited: Option<(Uid, usize)>,
+}
+
+impl<Uid> UidIndex<Uid> {
+    /// Creates an empty index, which will be built on the first lookup
+    pub fn new() -> Self {
+        UidIndex { positions: OnceLock::new(), edited: None }
+    }
+}
+
+impl<Uid: Hash + Eq + Clone> UidIndex<Uid> {
+    /// Returns the position of the first row with the given uid
+    pub fn get<Row>(&self, uid: &Uid, rows: &[Row], get_id: impl Fn(&Row) -> Uid) -> Option<usize> {
+        if let Some((edited_uid, position)) = &self.edited {
+            if get_id(&rows[*position]) != *edited_uid {
+                // The index is outdated until the next change to the table
+                return rows.iter().position(|row| get_id(row) == *uid);
+            }
+        }
+        self.positions(rows, get_id).get(uid).copied()
+    }
+
+    /// Updates the index after a row was pushed to the end of the table
+    pub fn push(&mut self, uid: Uid, position: usize) {
+        if let Some(positions) = self.positions.get_mut() {
+            positions.entry(uid).or_insert(position);
+        }
+    }
+
+    /// Updates the index before `row` is inserted at `position`, moving the rows after it down
+    pub fn insert<Row>(&mut self, position: usize, row: &Row, rows: &[Row], get_id: impl Fn(&Row) -> Uid) {
+        self.apply_edit(rows, &get_id);
+        if let Some(positions) = self.positions.get_mut() {
+            positions.values_mut()
+                .filter(|p| **p >= position)
+                .for_each(|p| *p += 1);
+            let first = positions.entry(get_id(row)).or_insert(position);
+            *first = (*first).min(position);
+        }
+    }
+    
+    /// Updates the index before the row at `position` is removed, moving the rows after it up
+    pub fn remove<Row>(&mut self, position: usize, rows: &[Row], get_id: impl Fn(&Row) -> Uid) {
+        self.apply_edit(rows, &get_id);
+        if let Some(positions) = self.positions.get_mut() {
+            let uid = get_id(&rows[position]);
+            if positions.get(&uid) == Some(&position) {
+                // Another row with the same uid becomes the first one
+                match rows[position + 1..].iter().position(|row| get_id(row) == uid) {
+                    Some(next) => { positions.insert(uid, position + next + 1); }
+                    None => { positions.remove(&uid); }
+                }
+            }
+            positions.values_mut()
+                .filter(|p| **p > position)
+                .for_each(|p| *p -= 1);
+        }
+    }
+    
+    /// Tells the index that the row at `position` is borrowed mutably and its uid might change
+    pub fn edit<Row>(&mut self, position: usize, rows: &[Row], get_id: impl Fn(&Row) -> Uid) {
+        self.apply_edit(rows, &get_id);
+        if self.positions.get().is_some() {
+            self.edited = Some((get_id(&rows[position]), position));
+        }
+    }
+
+    /// Removes the index, it will be built again on the next lookup
+    pub fn invalidate(&mut self) {
+        self.positions = OnceLock::new();
+        self.edited = None;
+    }
+
+    /// Invalidates the index if the uid of the last edited row has changed
+    fn apply_edit<Row>(&mut self, rows: &[Row], get_id: impl Fn(&Row) -> Uid) {
+        if let Some((uid, position)) = self.edited.take() {
+            if get_id(&rows[position]) != uid {
+                self.invalidate();
+            }
+        }
+    }
+
+    fn positions<Row>(&self, rows: &[Row], get_id: impl Fn(&Row) -> Uid) -> &HashMap<Uid, usize> {
+        self.positions.get_or_init(|| {
+            let mut positions = HashMap::with_capacity(rows.len());
+            rows.iter().enumerate().for_each(|(position, row)| {
+                positions.entry(get_id(row)).or_insert(position);
+            });
+            positions
+        })
+    }
+}
+
+impl<Uid> Default for UidIndex<Uid> {
+    fn default() -> Self {
+        UidIndex::new()
+    }
+}
+
+impl<Uid: Hash + Eq + Clone> Clone for UidIndex<Uid> {
+    fn clone(&self) -> Self {
+        UidIndex { positions: self.positions.clone(), edited: self.edited.clone() }
+    }
+}
+
+/// The index only caches information about the rows, so two indices are always equal. This way,
+/// tables can still derive `PartialEq` and `Hash`.
+impl<Uid> PartialEq for UidIndex<Uid> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<Uid> Eq for UidIndex<Uid> {}
+
+impl<Uid> Hash for UidIndex<Uid> {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
+
+impl<Uid> fmt::Debug for UidIndex<Uid> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UidIndex")
+            .field("built", &self.positions.get().is_some())
+            .finish()
+    }
+}
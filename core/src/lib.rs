@@ -1,6 +1,21 @@
 //! Simple Tables Core
 
+pub mod csv;
 pub mod error;
+pub mod html;
+pub mod index;
+pub mod markdown;
+pub mod render;
+#[cfg(feature = "serde")]
+pub mod serde_columns;
+pub mod sort;
+pub mod style;
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+#[cfg(feature = "serde")]
+pub use serde;
 
 // Trait
 pub trait TableRow {
@@ -9,14 +24,35 @@ pub trait TableRow {
     /// Returns a vector containing the types of the fields
     fn get_field_types() -> Vec<&'static str>;
     // TODO? add a function to return a map of the fields and their types
+    /// Returns the values of the fields of this row as strings, in the same order as
+    /// [`get_fields`](crate::TableRow::get_fields)
+    fn get_field_str(&self) -> Vec<String>;
+    /// Returns the alignment of each field when rendered. By default, integer and float fields are
+    /// aligned to the right and all other fields to the left.
+    fn get_field_alignments() -> Vec<render::Alignment> {
+        Self::get_field_types().into_iter().map(render::Alignment::for_type).collect()
+    }
     /// Returns the amount of fields in this struct
-    fn field_count() -> usize { Self::get_fields().iter().count() }
+    fn field_count() -> usize { Self::get_fields().len() }
+}
+
+/// A [`TableRow`](crate::TableRow) that can be created from the string representation of its
+/// fields. The `table_row` macro implements this trait for rows of which all field types
+/// implement `FromStr`, or are parsed using `#[column(parse_with = ...)]`. Rows with a column
+/// that uses `with` but not `parse_with` don't implement it.
+pub trait ParseTableRow: TableRow + Sized {
+    /// Parses a row from the string representation of its fields, given in the same order as
+    /// [`get_fields`](crate::TableRow::get_fields). When a field can't be parsed, the name of the
+    /// field and the reason are returned.
+    fn parse_fields(fields: &[&str]) -> Result<Self, (&'static str, String)>;
+    /// Returns the fields that can be missing from the input. An empty string is passed to
+    /// [`parse_fields`](crate::ParseTableRow::parse_fields) for a missing field.
+    fn get_optional_fields() -> Vec<&'static str> { Vec::new() }
 }
 
 /// A table should conform to this trait. `Row` is the table's row type.
 pub trait Table<Row: TableRow> {
     // Maybe not needed here: const UID: String;
-    // TODO: to_string() and fmt() using pretty ASCII tables. Also have a max column size => wrap lines, but that's for later
     // /// Gets the highest width of each element in the table, used for debugging and printing
     // fn get_sizes(&self) -> Vec<isize>;
     /// Gets the column size of a specific column. Requires that the column can be converted to a
@@ -24,8 +60,10 @@ pub trait Table<Row: TableRow> {
     ///
     /// # Examples
     /// ```rust
+    /// # use simple_tables::Table;
     /// # use simple_tables::macros::{table_row, table};
     /// # #[table_row]
+    /// # #[derive(Clone)]
     /// # struct TableRow {
     /// #     id: u32,
     /// #     name: String
@@ -47,27 +85,61 @@ pub trait Table<Row: TableRow> {
             let size = get_size(col);
             sizes.push(size);
         }
-        match sizes.iter().max() {
-            Some(max) => Some(max.to_owned()),
-            None => None
-        }
+        sizes.iter().max().copied()
     }
     /// Creates a new empty `Table`
     fn new() -> Self;
-    
-    // fn to_string(&self) -> String {
-    //     let field_names = Row::get_fields();
-    //     let field_lenghts = /*for all fields self.get_column_size(/*TODO*/ |row| { 1 });*/ vec![5,5,5];
-    //     let cols: Vec<String> = self.get_column(|row| row.to_string());
-    //
-    //     String::from("")
-    // }
-    /// Creates a new `Table` with an initial value for the rows
-    fn from_vec(vec: &Vec<Row>) -> Self;
+
+    /// Creates a new `Table` with a copy of the given rows. Use
+    /// [`from_rows`](crate::Table::from_rows) for rows that can't be cloned.
+    #[allow(clippy::ptr_arg)]
+    fn from_vec(vec: &Vec<Row>) -> Self
+        where Self: Sized, Row: Clone
+    {
+        Self::from_rows(vec.to_vec())
+    }
+    /// Creates a new `Table` that takes ownership of the given rows
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::Table;
+    /// # use simple_tables::macros::{table_row, table};
+    /// #[table_row]
+    /// struct Task {
+    ///     name: String,
+    ///     // `Box<dyn Fn()>` doesn't implement `Clone`
+    ///     #[column(skip)]
+    ///     run: Box<dyn Fn() -> u32>,
+    /// }
+    ///
+    /// #[table(rows = Task)]
+    /// struct Tasks {}
+    ///
+    /// let tasks = Tasks::from_rows(vec![Task { name: "answer".to_string(), run: Box::new(|| 42) }]);
+    /// assert_eq!(42, (tasks.get_row_at(0).unwrap().run)());
+    /// ```
+    fn from_rows(rows: Vec<Row>) -> Self
+        where Self: Sized
+    {
+        let mut table = Self::new();
+        for row in rows {
+            table.push(row);
+        }
+        table
+    }
     /// Returns an immutable reference to the rows of this table
     fn get_rows(&self) -> &Vec<Row>;
-    /// Returns a mutable reference to the rows of this table
+    /// Returns a mutable reference to the rows of this table. Because the rows can be edited, the
+    /// table is no longer considered [sorted](crate::IdTable::sort) afterwards.
     fn get_rows_mut(&mut self) -> &mut Vec<Row>;
+    /// Returns whether the rows are known to be sorted on their uid. Implemented by the `table`
+    /// macro for [`IdTable`](crate::IdTable), not meant to be used directly.
+    #[doc(hidden)]
+    fn is_sorted(&self) -> bool { false }
+    /// Marks the rows as sorted or not sorted on their uid. Implemented by the `table` macro for
+    /// [`IdTable`](crate::IdTable), not meant to be used directly.
+    #[doc(hidden)]
+    fn set_sorted(&mut self, _sorted: bool) {}
     /// Pushes a new row to the end of the table
     fn push(&mut self, row: Row) { self.get_rows_mut().push(row); }
     /// Inserts a new row at the top of the table (element 0)
@@ -78,8 +150,10 @@ pub trait Table<Row: TableRow> {
     ///
     /// # Example
     /// ```rust
+    /// # use simple_tables::Table;
     /// # use simple_tables::macros::{table_row, table};
     /// # #[table_row]
+    /// # #[derive(Clone)]
     /// # struct TableRow {
     /// #     id: u32,
     /// #     name: String
@@ -100,7 +174,7 @@ pub trait Table<Row: TableRow> {
     )
         -> Vec<ColumnType>
     {
-        let columns: Vec<ColumnType> = self.get_rows().to_owned().into_iter().map(|row| {
+        let columns: Vec<ColumnType> = self.get_rows().iter().map(|row| {
             column(row)
         }).collect();
         columns
@@ -115,12 +189,198 @@ pub trait Table<Row: TableRow> {
         self.get_rows_mut().remove(i)
     }
 
-    // TODO
-    // /// Sorts the rows based on a specific column
-    // fn sort_on(&mut self, based_on: &str);
-    // /// Returns a sorted copy of the rows.<br/>
-    // /// The rows are sorted based on a column.
-    // fn get_sorted(&self, based_on: &str) -> Vec<Row>;
+    /// Sorts the rows in ascending order based on the column with the name `based_on`. The sort is
+    /// stable. Returns an error if there is no column with this name.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::Table;
+    /// # use simple_tables::macros::{table_row, table};
+    /// # #[table_row]
+    /// # #[derive(Clone)]
+    /// # struct TableRow {
+    /// #     id: u32,
+    /// #     name: String
+    /// # }
+    /// #
+    /// # #[table(rows = TableRow)]
+    /// # struct MyTable {}
+    /// #
+    /// let mut table = MyTable::from_vec(&vec![TableRow { id: 2, name: "Opeth".to_string() }, TableRow { id: 1, name: "Slipknot".to_string() }]);
+    /// table.sort_on("id").unwrap();
+    /// assert_eq!(vec![1, 2], table.get_column(|row| row.id));
+    /// ```
+    fn sort_on(&mut self, based_on: &str) -> Result<(), error::TableError>
+        where Row: sort::CompareFields
+    {
+        self.sort_on_columns(&[(based_on, sort::Order::Ascending)])
+    }
+
+    /// Sorts the rows based on multiple columns, e.g. `&[("country", Order::Ascending), ("name",
+    /// Order::Ascending)]` sorts on the country and then on the name of rows in the same country.
+    /// The sort is stable. Returns an error if one of the columns doesn't exist.
+    fn sort_on_columns(&mut self, columns: &[(&str, sort::Order)]) -> Result<(), error::TableError>
+        where Row: sort::CompareFields
+    {
+        check_columns::<Row>(columns)?;
+        self.get_rows_mut().sort_by(|a, b| sort::compare_rows(a, b, columns));
+        Ok(())
+    }
+
+    /// Sorts the rows using a key extraction function. The sort is stable. To sort in descending
+    /// order, wrap the key in [`Reverse`](std::cmp::Reverse).
+    fn sort_by_key<Key: Ord, F: FnMut(&Row) -> Key>(&mut self, key: F) {
+        self.get_rows_mut().sort_by_key(key);
+    }
+
+    /// Sorts the rows using a comparator function. The sort is stable.
+    fn sort_by<F: FnMut(&Row, &Row) -> std::cmp::Ordering>(&mut self, compare: F) {
+        self.get_rows_mut().sort_by(compare);
+    }
+
+    /// Returns a sorted copy of the rows.<br/>
+    /// The rows are sorted in ascending order based on a column. Returns an error if there is no
+    /// column with this name.
+    fn get_sorted(&self, based_on: &str) -> Result<Vec<Row>, error::TableError>
+        where Row: sort::CompareFields + Clone
+    {
+        self.get_sorted_on_columns(&[(based_on, sort::Order::Ascending)])
+    }
+
+    /// Returns a copy of the rows, sorted based on multiple columns. See
+    /// [`sort_on_columns`](crate::Table::sort_on_columns).
+    fn get_sorted_on_columns(&self, columns: &[(&str, sort::Order)]) -> Result<Vec<Row>, error::TableError>
+        where Row: sort::CompareFields + Clone
+    {
+        check_columns::<Row>(columns)?;
+        let mut rows = self.get_rows().clone();
+        rows.sort_by(|a, b| sort::compare_rows(a, b, columns));
+        Ok(rows)
+    }
+
+    /// Renders the table as a string using the borders of the given
+    /// [`TableStyle`](crate::style::TableStyle). `to_string()` uses
+    /// [`TableStyle::ASCII`](crate::style::TableStyle::ASCII).
+    fn render_with(&self, style: &style::TableStyle) -> String {
+        render::Renderer::new(*style).render(self.get_rows())
+    }
+
+    /// Renders the table as a GitHub-flavored Markdown table. See
+    /// [`markdown::to_markdown`](crate::markdown::to_markdown).
+    fn to_markdown(&self) -> String {
+        markdown::to_markdown(self.get_rows())
+    }
+
+    /// Renders the table as an HTML `<table>`. See [`html::to_html`](crate::html::to_html).
+    fn to_html(&self) -> String {
+        html::to_html(self.get_rows(), &html::HtmlOptions::default())
+    }
+
+    /// Renders the table as an HTML `<table>`, using the `id`, `class` and column classes in
+    /// `options`
+    fn to_html_with(&self, options: &html::HtmlOptions) -> String {
+        html::to_html(self.get_rows(), options)
+    }
+
+    /// Writes the table as CSV to `writer`, including a header with the field names. See
+    /// [`csv`](crate::csv) for how fields are quoted.
+    fn write_csv<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        csv::write_delimited(self.get_rows(), writer, ',')
+    }
+
+    /// Writes the table as TSV (tab-separated values) to `writer`, including a header with the
+    /// field names
+    fn write_tsv<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        csv::write_delimited(self.get_rows(), writer, '\t')
+    }
+
+    /// Writes the table to `writer`, separating fields with `delimiter`, including a header with
+    /// the field names
+    fn write_delimited<W: std::io::Write>(&self, writer: W, delimiter: char) -> std::io::Result<()> {
+        csv::write_delimited(self.get_rows(), writer, delimiter)
+    }
+
+    /// Returns the table as CSV, including a header with the field names
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::Table;
+    /// # use simple_tables::macros::{table_row, table};
+    /// # #[table_row]
+    /// # #[derive(Clone)]
+    /// # struct TableRow {
+    /// #     id: u32,
+    /// #     name: String
+    /// # }
+    /// #
+    /// # #[table(rows = TableRow)]
+    /// # struct MyTable {}
+    /// #
+    /// let table = MyTable::from_vec(&vec![TableRow { id: 1, name: "\"Weird Al\" Yankovic".to_string() }]);
+    /// assert_eq!("id,name\r\n1,\"\"\"Weird Al\"\" Yankovic\"\r\n", table.to_csv());
+    /// ```
+    fn to_csv(&self) -> String {
+        let mut csv: Vec<u8> = Vec::new();
+        self.write_csv(&mut csv).expect("Writing to a Vec can't fail");
+        String::from_utf8(csv).expect("The table contains valid UTF-8")
+    }
+
+    /// Returns the table as TSV (tab-separated values), including a header with the field names
+    fn to_tsv(&self) -> String {
+        let mut tsv: Vec<u8> = Vec::new();
+        self.write_tsv(&mut tsv).expect("Writing to a Vec can't fail");
+        String::from_utf8(tsv).expect("The table contains valid UTF-8")
+    }
+
+    /// Creates a new table from CSV. The first line should be a header containing the names of
+    /// the columns, which are mapped to the fields of the rows by name. Columns that are not a
+    /// field of the rows are ignored.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::Table;
+    /// # use simple_tables::macros::{table_row, table};
+    /// # #[table_row]
+    /// # struct TableRow {
+    /// #     id: u32,
+    /// #     name: String
+    /// # }
+    /// #
+    /// # #[table(rows = TableRow)]
+    /// # struct MyTable {}
+    /// #
+    /// let csv = "name,id\nOpeth,1\n\"Crosby, Stills & Nash\",2\n";
+    /// let table = MyTable::from_csv_reader(csv.as_bytes()).unwrap();
+    /// assert_eq!(2, table.get_row_at(1).unwrap().id);
+    ///
+    /// let error = MyTable::from_csv_reader("name,id\nOpeth,one".as_bytes()).unwrap_err();
+    /// assert_eq!("Could not parse column `id` of row 0 (line 2): invalid digit found in string", error.to_string());
+    /// ```
+    fn from_csv_reader<R: std::io::Read>(reader: R) -> Result<Self, error::TableError>
+        where Self: Sized, Row: ParseTableRow
+    {
+        Self::from_delimited_reader(reader, ',')
+    }
+
+    /// Creates a new table from TSV (tab-separated values). See
+    /// [`from_csv_reader`](crate::Table::from_csv_reader).
+    fn from_tsv_reader<R: std::io::Read>(reader: R) -> Result<Self, error::TableError>
+        where Self: Sized, Row: ParseTableRow
+    {
+        Self::from_delimited_reader(reader, '\t')
+    }
+
+    /// Creates a new table from input of which the fields are separated by `delimiter`. See
+    /// [`from_csv_reader`](crate::Table::from_csv_reader).
+    fn from_delimited_reader<R: std::io::Read>(reader: R, delimiter: char) -> Result<Self, error::TableError>
+        where Self: Sized, Row: ParseTableRow
+    {
+        let mut table = Self::new();
+        for row in csv::read_delimited(reader, delimiter)? {
+            table.push(row);
+        }
+        Ok(table)
+    }
 
     fn column_count(&self) -> usize { Row::field_count() }
     fn row_count(&self) -> usize { self.get_rows().len() }
@@ -130,7 +390,9 @@ pub trait Table<Row: TableRow> {
 /// Defines a table with a unique identifier. This class should be implemented alongside the
 /// [`Table`](crate::Table) trait.
 ///
-/// When you have a table with a uid, this trait has to be implemented manually for now.
+/// The `table` macro implements this trait when a uid field is given, e.g.
+/// `#[table(rows = MyTableRow, uid = "id")]`. The uid field has to implement `Clone` and
+/// `PartialEq`. Otherwise, this trait can be implemented manually.
 ///
 /// # To implement
 /// - [`get_id_from_row`](crate::IdTable)
@@ -145,7 +407,8 @@ pub trait Table<Row: TableRow> {
 ///   already implemented for your struct. When you run your program, it will actually compile and
 ///   run.
 pub trait IdTable<UidType: PartialEq, Row: TableRow>: Table<Row> {
-    /// Gets the uid from a row, this should be implemented manually (for now) for structs with uids.
+    /// Gets the uid from a row. The `table` macro implements this when a uid field is given, e.g.
+    /// `#[table(rows = MyTableRow, uid = "id")]`. Otherwise, it can be implemented manually.
     ///
     /// # Example
     /// ```rust
@@ -161,7 +424,7 @@ pub trait IdTable<UidType: PartialEq, Row: TableRow>: Table<Row> {
     /// struct MyTable {}
     ///
     /// impl simple_tables::IdTable<i32, MyTableRow> for MyTable {
-    ///     fn get_id_from_row(row: i32) -> i32 {
+    ///     fn get_id_from_row(row: &MyTableRow) -> i32 {
     ///         row.id
     ///     }
     /// }
@@ -171,12 +434,8 @@ pub trait IdTable<UidType: PartialEq, Row: TableRow>: Table<Row> {
     
     /// Returns the row with the specific uid
     fn get_row(&self, uid: UidType) -> Option<&Row> {
-        let val: Option<&Row> = self.get_rows().iter().find_map(|row| {
-            if Self::get_id_from_row(row) == uid {
-                Some(row)
-            }  else {
-                None
-            }
+        let val: Option<&Row> = self.get_rows().iter().find(|row| {
+            Self::get_id_from_row(row) == uid
         });
         
         val
@@ -217,26 +476,197 @@ pub trait IdTable<UidType: PartialEq, Row: TableRow>: Table<Row> {
         }
     }
     
-    // TODO
-    // /// Searches through the sorted rows using the uid. Only works if the rows have been sorted first,
-    // /// the [`sort`](table_rows::core::Table::sort) function can help with this.
-    // fn sorted_search(&self, uid: V) -> Option<Row>;
-    // /// Sorts the rows based on the `uid`
-    // ///
-    // /// # Panics
-    // /// if there is no `uid` set
-    // fn sort(&mut self);
+    /// Pushes a new row to the end of the table, unless there already is a row with the same uid.
+    ///
+    /// # Errors
+    /// [`DuplicateUid`](crate::error::TableErrorKind::DuplicateUid) if there is a row with the
+    /// same uid, the table is left unchanged.
+    fn try_push(&mut self, row: Row) -> Result<(), crate::error::TableError> {
+        check_unique_uid(self, &row)?;
+        self.push(row);
+        Ok(())
+    }
+    
+    /// Inserts a new row at index `i`, unless there already is a row with the same uid.
+    ///
+    /// # Errors
+    /// [`DuplicateUid`](crate::error::TableErrorKind::DuplicateUid) if there is a row with the
+    /// same uid, the table is left unchanged.
+    fn try_insert(&mut self, i: usize, row: Row) -> Result<(), crate::error::TableError> {
+        check_unique_uid(self, &row)?;
+        self.insert(i, row);
+        Ok(())
+    }
+    
+    /// Replaces the row with the same uid as `row` and returns the replaced row. When there is no
+    /// row with this uid, the row is pushed to the end of the table and `None` is returned.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::{IdTable, Table};
+    /// # use simple_tables::macros::{table_row, table};
+    /// # #[table_row]
+    /// # #[derive(Clone)]
+    /// # struct TableRow {
+    /// #     id: u32,
+    /// #     name: String
+    /// # }
+    /// #
+    /// # #[table(rows = TableRow, uid = "id")]
+    /// # struct MyTable {}
+    /// let mut table = MyTable::from_vec(&vec![TableRow { id: 1, name: "Opeth".to_string() }]);
+    /// let replaced = table.upsert(TableRow { id: 1, name: "Gojira".to_string() });
+    /// assert_eq!("Opeth", replaced.unwrap().name);
+    /// assert!(table.upsert(TableRow { id: 2, name: "Tool".to_string() }).is_none());
+    /// assert_eq!(2, table.row_count());
+    /// ```
+    fn upsert(&mut self, row: Row) -> Option<Row> {
+        // Replacing a row with one with the same uid doesn't change the order
+        let sorted = self.is_sorted();
+        match self.get_row_mut(Self::get_id_from_row(&row)) {
+            Some(existing) => {
+                let replaced = std::mem::replace(existing, row);
+                self.set_sorted(sorted);
+                Some(replaced)
+            },
+            None => {
+                self.push(row);
+                None
+            }
+        }
+    }
+    
+    /// Sorts the rows based on their uid. Afterwards, the table is considered sorted until rows
+    /// are added or edited in a way that could break the order.
+    fn sort(&mut self) where UidType: Ord {
+        self.get_rows_mut().sort_by_key(|row| Self::get_id_from_row(row));
+        self.set_sorted(true);
+    }
+    
+    /// Returns the first row with the specific uid. When the table is [sorted](crate::IdTable::sort),
+    /// this uses a binary search, otherwise it falls back to [`get_row`](crate::IdTable::get_row).
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::{IdTable, Table};
+    /// # use simple_tables::macros::{table_row, table};
+    /// # #[table_row]
+    /// # #[derive(Clone)]
+    /// # struct TableRow {
+    /// #     id: u32,
+    /// #     name: String
+    /// # }
+    /// #
+    /// # #[table(rows = TableRow)]
+    /// # struct MyTable {}
+    /// #
+    /// # impl IdTable<u32, TableRow> for MyTable {
+    /// #     fn get_id_from_row(row: &TableRow) -> u32 { row.id }
+    /// # }
+    /// let mut table = MyTable::from_vec(&vec![TableRow { id: 2, name: "Opeth".to_string() }, TableRow { id: 1, name: "Slipknot".to_string() }]);
+    /// table.sort();
+    /// assert_eq!("Opeth", table.sorted_search(2).unwrap().name);
+    /// ```
+    fn sorted_search(&self, uid: UidType) -> Option<&Row> where UidType: Ord {
+        if self.is_sorted() {
+            // Duplicate uids are next to each other, find the first one
+            let rows = self.get_rows();
+            let index = rows.partition_point(|row| Self::get_id_from_row(row) < uid);
+            rows.get(index).filter(|row| Self::get_id_from_row(row) == uid)
+        } else {
+            self.get_row(uid)
+        }
+    }
+    
+    /// Inserts a row while keeping the table [sorted](crate::IdTable::sort). The row is
+    /// inserted after the rows with the same uid. When the table isn't sorted, the row is pushed
+    /// to the end of the table.
+    fn insert_sorted(&mut self, row: Row) where UidType: Ord {
+        if self.is_sorted() {
+            let uid = Self::get_id_from_row(&row);
+            let index = self.get_rows().partition_point(|other| Self::get_id_from_row(other) <= uid);
+            self.insert(index, row);
+            self.set_sorted(true);
+        } else {
+            self.push(row);
+        }
+    }
+}
+
+/// Gives access to a field of a row. The name of the field is encoded in the `Name` type as a tuple
+/// of [`FieldChar`]s, e.g. `(FieldChar<'i'>, FieldChar<'d'>)` for a field named `id`.
+///
+/// This is implemented for every field by the `table_row` macro, so that the `table` macro can find
+/// the type of the uid field.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't have the field that is used as uid",
+    label = "the uid has to be the name of a field of `{Self}`",
+    note = "the name of the field is encoded as a tuple with a `FieldChar` for every character"
+)]
+pub trait RowField<Name> {
+    /// The type of the field
+    type Type;
+    
+    /// Returns a reference to the field
+    fn get_field(&self) -> &Self::Type;
+}
+
+/// A single character of a field name, see [`RowField`]
+#[doc(hidden)]
+pub struct FieldChar<const C: char>;
+
+/// Fails to compile if `Row` doesn't have the field `Name`. Used by the `table` macro to report a
+/// uid that isn't a field of the rows.
+#[doc(hidden)]
+pub const fn check_uid_field<Row: RowField<Name>, Name>() {}
+
+/// Returns the name of a column of a flattened field, e.g. `address.street` for the column
+/// `street` of the field `address`.
+///
+/// Field names have to be `&'static str`, so every name is only allocated once and then reused.
+#[doc(hidden)]
+pub fn prefixed_field(prefix: &str, field: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let name = format!("{}.{}", prefix, field);
+    let mut names = NAMES.get_or_init(Default::default).lock().unwrap_or_else(|err| err.into_inner());
+    match names.get(name.as_str()) {
+        Some(name) => name,
+        None => {
+            let name: &'static str = Box::leak(name.into_boxed_str());
+            names.insert(name);
+            name
+        }
+    }
+}
+
+/// Returns the names of the columns of a flattened field, see [`prefixed_field`]
+#[doc(hidden)]
+pub fn prefixed_fields(prefix: &str, fields: Vec<&'static str>) -> Vec<&'static str> {
+    fields.into_iter().map(|field| prefixed_field(prefix, field)).collect()
+}
+
+/// Returns an error if there already is a row in `table` with the same uid as `row`
+fn check_unique_uid<UidType: PartialEq, Row: TableRow, T: IdTable<UidType, Row> + ?Sized>(table: &T, row: &Row) -> Result<(), error::TableError> {
+    if table.get_row_index(T::get_id_from_row(row)).is_some() {
+        Err(error::TableError { kind: error::TableErrorKind::DuplicateUid, message: "There already is a table row with this uid".to_string() })
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an error if one of the columns isn't a field of `Row`
+fn check_columns<Row: TableRow>(columns: &[(&str, sort::Order)]) -> Result<(), error::TableError> {
+    let fields = Row::get_fields();
+    match columns.iter().find(|(column, _)| !fields.contains(column)) {
+        Some((column, _)) => Err(error::TableError {
+            kind: error::TableErrorKind::UnknownColumn,
+            message: format!("There is no column `{}`", column)
+        }),
+        None => Ok(())
+    }
 }
 
 fn get_size<Type: ToString>(var: Type) -> usize {
-    // let type_id = var.type_id();
-    let len = var.to_string().chars().into_iter().count();
-    len
-    // if type_id == TypeId::of::<String>() {
-    //     (var as String).chars().into_iter().count();
-    // } else if type_id == TypeId::of::<str>() {
-    //     (var as str).chars().into_iter().count();
-    // } else if type_id == TypeId::of::<usize> {
-    //     (var as usize)
-    // }
-}
\ No newline at end of file
+    render::display_width(&var.to_string())
+}
@@ -0,0 +1,73 @@
+//! Exporting tables as GitHub-flavored Markdown.
+
+use crate::render::{display_width, Alignment};
+use crate::TableRow;
+
+/// Renders the rows as a GitHub-flavored Markdown table, with a header containing the
+/// [field names](crate::TableRow::get_fields) of the row type.
+///
+/// Columns are aligned according to
+/// [`get_field_alignments`](crate::TableRow::get_field_alignments), pipes inside of cells are
+/// escaped and newlines are replaced by `<br>`.
+///
+/// # Example
+/// ```rust
+/// # use simple_tables::macros::table_row;
+/// #[table_row]
+/// struct MyTableRow {
+///     id: u32,
+///     name: String
+/// }
+///
+/// let rows = vec![MyTableRow { id: 1, name: "Simon | Garfunkel".to_string() }];
+/// let s = simple_tables::markdown::to_markdown(&rows);
+/// assert_eq!(s, "\
+/// |  id | name               |
+/// | --: | :----------------- |
+/// |   1 | Simon \\| Garfunkel |");
+/// ```
+pub fn to_markdown<Row: TableRow>(rows: &[Row]) -> String {
+    let headers: Vec<String> = Row::get_fields().into_iter().map(escape).collect();
+    let cells: Vec<Vec<String>> = rows.iter().map(|row| {
+        row.get_field_str().iter().map(|cell| escape(cell)).collect()
+    }).collect();
+    let alignments = Row::get_field_alignments();
+
+    // The sizes of the columns, a column is at least as wide as the alignment marker
+    let mut column_sizes: Vec<usize> = headers.iter().map(|header| display_width(header).max(3)).collect();
+    cells.iter().for_each(|row_val| {
+        row_val.iter().enumerate().for_each(|(col, col_val)| {
+            let len = display_width(col_val);
+            if column_sizes[col] < len {
+                column_sizes[col] = len;
+            }
+        });
+    });
+
+    let line = |cells: Vec<String>| -> String {
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut lines: Vec<String> = Vec::new();
+    lines.push(line(headers.iter().enumerate().map(|(col, header)| {
+        alignments[col].pad(header, column_sizes[col])
+    }).collect()));
+    lines.push(line(alignments.iter().enumerate().map(|(col, alignment)| {
+        let dashes = column_sizes[col] - 1;
+        match alignment {
+            Alignment::Left => format!(":{}", "-".repeat(dashes)),
+            Alignment::Right => format!("{}:", "-".repeat(dashes)),
+            Alignment::Center => format!(":{}:", "-".repeat(dashes - 1)),
+        }
+    }).collect()));
+    cells.iter().for_each(|row_val| {
+        lines.push(line(row_val.iter().enumerate().map(|(col, cell)| {
+            alignments[col].pad(cell, column_sizes[col])
+        }).collect()));
+    });
+    lines.join("\n")
+}
+
+/// Escapes the characters in a cell that would break the Markdown table
+fn escape(cell: &str) -> String {
+    cell.replace('|', "\\|").replace("\r\n", "<br>").replace('\n', "<br>")
+}
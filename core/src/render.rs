@@ -0,0 +1,368 @@
+//! Rendering of tables to formatted text.
+
+use crate::style::{HorizontalLine, TableStyle};
+use crate::TableRow;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+/// The horizontal alignment of the cells in a column
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Right,
+    Center,
+}
+
+impl Alignment {
+    /// Returns the default alignment for a column of the given type. Integer and float types are
+    /// aligned to the right, all other types to the left.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_tables::render::Alignment;
+    ///
+    /// assert_eq!(Alignment::Right, Alignment::for_type("u32"));
+    /// assert_eq!(Alignment::Left, Alignment::for_type("String"));
+    /// ```
+    pub fn for_type(type_name: &str) -> Alignment {
+        match type_name {
+            "i8" | "i16" | "i32" | "i64" | "i128" | "isize"
+            | "u8" | "u16" | "u32" | "u64" | "u128" | "usize"
+            | "f32" | "f64" => Alignment::Right,
+            _ => Alignment::Left,
+        }
+    }
+
+    /// Pads a string with spaces up to the given width, according to this alignment
+    pub(crate) fn pad(&self, s: &str, width: usize) -> String {
+        let left_over = width.saturating_sub(display_width(s));
+        let (before, after) = match self {
+            Alignment::Left => (0, left_over),
+            Alignment::Right => (left_over, 0),
+            Alignment::Center => (left_over / 2, left_over - left_over / 2),
+        };
+        format!("{}{}{}", " ".repeat(before), s, " ".repeat(after))
+    }
+}
+
+/// Renders rows of a [`Table`](crate::Table) to a formatted text table.
+///
+/// This is what the `Display` and `Debug` implementations generated by the `table` macro use, and
+/// what [`Table::render_with`](crate::Table::render_with) uses.
+///
+/// # Example
+/// ```rust
+/// # use simple_tables::macros::table_row;
+/// use simple_tables::render::Renderer;
+/// use simple_tables::style::TableStyle;
+///
+/// #[table_row]
+/// struct MyTableRow {
+///     id: u32,
+///     name: String
+/// }
+///
+/// let rows = vec![MyTableRow { id: 1, name: "Opeth".to_string() }];
+/// let s = Renderer::new(TableStyle::PSQL).render(&rows);
+/// assert_eq!(s, "\
+/// id | name
+/// ---+------
+///  1 | Opeth");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Renderer {
+    /// The borders to use
+    pub style: TableStyle,
+    /// Alignments of columns that override the default alignment of those columns. Columns are
+    /// identified by their name.
+    pub alignments: Vec<(String, Alignment)>,
+    /// The maximum width of every column. Cells that are wider are wrapped over multiple lines.
+    pub max_width: Option<usize>,
+    /// Maximum widths of columns that override [`max_width`](Renderer::max_width) for those
+    /// columns. Columns are identified by their name.
+    pub max_widths: Vec<(String, usize)>,
+}
+
+impl Renderer {
+    /// Creates a new renderer using the given style
+    pub fn new(style: TableStyle) -> Self {
+        Renderer { style, alignments: Vec::new(), max_width: None, max_widths: Vec::new() }
+    }
+
+    /// Overrides the alignment of the column with the given name
+    ///
+    /// # Example
+    /// ```rust
+    /// # use simple_tables::macros::table_row;
+    /// use simple_tables::render::{Alignment, Renderer};
+    /// use simple_tables::style::TableStyle;
+    ///
+    /// #[table_row]
+    /// struct MyTableRow {
+    ///     id: u32,
+    ///     name: String
+    /// }
+    ///
+    /// let rows = vec![MyTableRow { id: 1, name: "Opeth".to_string() }];
+    /// let s = Renderer::new(TableStyle::PSQL).align("name", Alignment::Right).render(&rows);
+    /// assert_eq!(s, "\
+    /// id |  name
+    /// ---+------
+    ///  1 | Opeth");
+    /// ```
+    pub fn align(mut self, column: &str, alignment: Alignment) -> Self {
+        self.alignments.retain(|(name, _)| name != column);
+        self.alignments.push((column.to_string(), alignment));
+        self
+    }
+
+    /// Sets the maximum width of all columns. Cells that are wider are wrapped on word boundaries
+    /// over multiple lines. Words that are wider than the maximum width are broken up.
+    ///
+    /// # Example
+    /// ```rust
+    /// use simple_tables::render::Renderer;
+    ///
+    /// let cells = vec![vec!["Nick Cave & The Bad Seeds"]];
+    /// let s = Renderer::default().max_width(10).render_cells(&["name"], &cells);
+    /// assert_eq!(s, "\
+    /// +-----------+
+    /// | name      |
+    /// +===========+
+    /// | Nick Cave |
+    /// | & The Bad |
+    /// | Seeds     |
+    /// +-----------+");
+    /// ```
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Sets the maximum width of the column with the given name, overriding the width set with
+    /// [`max_width`](Renderer::max_width)
+    pub fn max_column_width(mut self, column: &str, width: usize) -> Self {
+        self.max_widths.retain(|(name, _)| name != column);
+        self.max_widths.push((column.to_string(), width));
+        self
+    }
+
+    /// Renders the rows with a header containing the [field names](crate::TableRow::get_fields)
+    /// of the row type. Columns are aligned according to
+    /// [`get_field_alignments`](crate::TableRow::get_field_alignments), unless overridden.
+    pub fn render<Row: TableRow>(&self, rows: &[Row]) -> String {
+        let cells: Vec<Vec<String>> = rows.iter().map(|row| row.get_field_str()).collect();
+        self.render_aligned(&Row::get_fields(), &Row::get_field_alignments(), &cells)
+    }
+
+    /// Renders a header and cells. Every row in `cells` should have as many cells as there are
+    /// headers. Columns are left aligned, unless overridden.
+    pub fn render_cells<S: AsRef<str>>(&self, headers: &[&str], cells: &[Vec<S>]) -> String {
+        self.render_aligned(headers, &vec![Alignment::Left; headers.len()], cells)
+    }
+
+    fn render_aligned<S: AsRef<str>>(&self, headers: &[&str], alignments: &[Alignment], cells: &[Vec<S>]) -> String {
+        let style = &self.style;
+        let alignments: Vec<Alignment> = headers.iter().zip(alignments).map(|(header, alignment)| {
+            self.alignments.iter()
+                .find(|(name, _)| name == header)
+                .map(|(_, alignment)| *alignment)
+                .unwrap_or(*alignment)
+        }).collect();
+        let max_widths: Vec<Option<usize>> = headers.iter().map(|header| {
+            self.max_widths.iter()
+                .find(|(name, _)| name == header)
+                .map(|(_, width)| *width)
+                .or(self.max_width)
+        }).collect();
+        // Every cell is split up into the lines it will be rendered on
+        let split_row = |row_val: &[&str]| -> Vec<Vec<String>> {
+            row_val.iter().zip(max_widths.iter()).map(|(cell_val, max_width)| {
+                split_lines(cell_val, *max_width)
+            }).collect()
+        };
+        let header_lines = split_row(headers);
+        let row_lines: Vec<Vec<Vec<String>>> = cells.iter().map(|row_val| {
+            let row_val: Vec<&str> = row_val.iter().map(|cell_val| cell_val.as_ref()).collect();
+            split_row(&row_val)
+        }).collect();
+        // The sizes of the columns, a column is at least as wide as its title
+        let mut column_sizes: Vec<usize> = vec![0; headers.len()];
+        std::iter::once(&header_lines).chain(row_lines.iter()).for_each(|row_val| {
+            row_val.iter().enumerate().for_each(|(col, col_val)| {
+                col_val.iter().for_each(|line| {
+                    let len = display_width(line);
+                    if column_sizes[col] < len {
+                        column_sizes[col] = len;
+                    }
+                });
+            });
+        });
+
+        let mut lines: Vec<String> = Vec::new();
+        if let Some(top) = &style.top {
+            lines.push(self.horizontal_line(top, &column_sizes));
+        }
+        lines.append(&mut self.cell_lines(&header_lines, &column_sizes, &alignments));
+        if let Some(header_separator) = &style.header_separator {
+            lines.push(self.horizontal_line(header_separator, &column_sizes));
+        }
+        let total_rows = row_lines.len();
+        row_lines.iter().enumerate().for_each(|(row, row_val)| {
+            lines.append(&mut self.cell_lines(row_val, &column_sizes, &alignments));
+            if row + 1 != total_rows {
+                if let Some(row_separator) = &style.row_separator {
+                    lines.push(self.horizontal_line(row_separator, &column_sizes));
+                }
+            }
+        });
+        if let Some(bottom) = &style.bottom {
+            lines.push(self.horizontal_line(bottom, &column_sizes));
+        }
+        lines.join("\n")
+    }
+
+    /// Renders a horizontal line spanning all columns
+    fn horizontal_line(&self, line: &HorizontalLine, column_sizes: &[usize]) -> String {
+        let style = &self.style;
+        let fill = line.fill.to_string().repeat(style.padding);
+        let total_columns = column_sizes.len();
+        let mut s = String::new();
+        if style.left.is_some() {
+            s.push(line.left);
+            s.push_str(&fill);
+        }
+        column_sizes.iter().enumerate().for_each(|(col, col_size)| {
+            s.push_str(&line.fill.to_string().repeat(*col_size));
+            if col + 1 != total_columns {
+                s.push_str(&fill);
+                if style.column_separator.is_some() {
+                    s.push(line.junction);
+                }
+                s.push_str(&fill);
+            }
+        });
+        if style.right.is_some() {
+            s.push_str(&fill);
+            s.push(line.right);
+        }
+        s
+    }
+
+    /// Renders a row of cells that can span multiple lines. Cells with less lines than the other
+    /// cells in the row are filled up with empty lines.
+    fn cell_lines(&self, cells: &[Vec<String>], column_sizes: &[usize], alignments: &[Alignment]) -> Vec<String> {
+        let height = cells.iter().map(|cell| cell.len()).max().unwrap_or(1);
+        (0..height).map(|i| {
+            let line: Vec<&str> = cells.iter().map(|cell| {
+                cell.get(i).map(|line| line.as_str()).unwrap_or("")
+            }).collect();
+            self.cell_line(&line, column_sizes, alignments)
+        }).collect()
+    }
+
+    /// Renders one line of cells, padding each cell to the size of its column
+    fn cell_line(&self, cells: &[&str], column_sizes: &[usize], alignments: &[Alignment]) -> String {
+        let style = &self.style;
+        let padding = " ".repeat(style.padding);
+        let total_columns = column_sizes.len();
+        let mut s = String::new();
+        if let Some(left) = style.left {
+            s.push(left);
+            s.push_str(&padding);
+        }
+        cells.iter().enumerate().for_each(|(col, cell_val)| {
+            s.push_str(&alignments[col].pad(cell_val, column_sizes[col]));
+            if col + 1 != total_columns {
+                s.push_str(&padding);
+                if let Some(separator) = style.column_separator {
+                    s.push(separator);
+                }
+                s.push_str(&padding);
+            }
+        });
+        if let Some(right) = style.right {
+            s.push_str(&padding);
+            s.push(right);
+        } else {
+            s.truncate(s.trim_end().len());
+        }
+        s
+    }
+}
+
+/// Returns the width of a string when it is printed in a terminal.
+///
+/// The width is based on the East Asian Width of the characters, so wide characters like CJK
+/// characters and most emoji count as two columns, while combining characters don't count at all.
+///
+/// # Example
+/// ```rust
+/// use simple_tables::render::display_width;
+///
+/// assert_eq!(5, display_width("Opeth"));
+/// assert_eq!(4, display_width("日本"));
+/// assert_eq!(4, display_width("Cafe\u{301}"));
+/// ```
+pub fn display_width(s: &str) -> usize {
+    s.graphemes(true).map(|grapheme| grapheme.width()).sum()
+}
+
+/// Splits a cell up into the lines it consists of, wrapping lines that are wider than `max_width`
+fn split_lines(cell: &str, max_width: Option<usize>) -> Vec<String> {
+    let mut lines: Vec<String> = cell.lines()
+        .flat_map(|line| match max_width {
+            Some(max_width) if display_width(line) > max_width => wrap(line, max_width.max(1)),
+            _ => vec![line.to_string()],
+        })
+        .collect();
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    lines
+}
+
+/// Wraps a line on word boundaries so that no line is wider than `max_width`. Words that are wider
+/// than `max_width` are broken up.
+fn wrap(line: &str, max_width: usize) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let mut word = word;
+        while display_width(word) > max_width {
+            if !current.is_empty() {
+                lines.push(std::mem::take(&mut current));
+            }
+            let (head, tail) = split_at_width(word, max_width);
+            lines.push(head.to_string());
+            word = tail;
+        }
+        if current.is_empty() {
+            current.push_str(word);
+        } else if display_width(&current) + 1 + display_width(word) <= max_width {
+            current.push(' ');
+            current.push_str(word);
+        } else {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        }
+    }
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits a string in a part that is at most `max_width` wide and the rest. Grapheme clusters are
+/// never split, the first part contains at least one grapheme cluster.
+fn split_at_width(s: &str, max_width: usize) -> (&str, &str) {
+    let mut total_width = 0;
+    let index = s.grapheme_indices(true)
+        .find(|(i, grapheme)| {
+            total_width += grapheme.width();
+            *i != 0 && total_width > max_width
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    s.split_at(index)
+}
@@ -0,0 +1,25 @@
+//! Serializing tables as a map of columns instead of a sequence of rows.
+//!
+//! Only available with the `serde` feature.
+
+use serde::{Deserializer, Serializer};
+use crate::TableRow;
+
+/// A [`TableRow`](crate::TableRow) of which a collection can be (de)serialized as a map from the
+/// [field names](crate::TableRow::get_fields) to the values of that field in each row. Fields
+/// with `#[serde(rename = "...")]` use that name and fields with `#[serde(skip)]` are left out,
+/// like when the rows are serialized.
+///
+/// The `table_row` macro implements this trait for rows declared using `#[table_row(serde)]`.
+/// Use `#[table(rows = MyTableRow, serde = "columns")]` to (de)serialize a table this way.
+///
+/// # Example
+/// ```json
+/// { "id": [1, 2], "name": ["Opeth", "Slipknot"] }
+/// ```
+pub trait SerdeColumns: TableRow + Sized {
+    /// Serializes the rows as a map of columns
+    fn serialize_columns<S: Serializer>(rows: &[Self], serializer: S) -> Result<S::Ok, S::Error>;
+    /// Deserializes rows from a map of columns. All columns should have the same length.
+    fn deserialize_columns<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Self>, D::Error>;
+}
@@ -0,0 +1,59 @@
+//! Sorting tables based on their columns.
+
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use crate::TableRow;
+
+/// The order to sort a column in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Order {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// A [`TableRow`](crate::TableRow) of which the fields can be compared by name, so tables of this
+/// row can be sorted on a column using [`sort_on`](crate::Table::sort_on).
+///
+/// The `table_row` macro implements this trait for rows of which all field types implement
+/// `PartialOrd`.
+pub trait CompareFields: TableRow {
+    /// Compares the field with the given name of two rows. Returns `None` if there is no field
+    /// with this name, or if the values can't be compared.
+    fn compare_field(&self, other: &Self, field: &str) -> Option<Ordering>;
+}
+
+/// Compares two rows based on multiple columns. The rows are compared on the first column, if
+/// they are equal there, on the second column and so on. Values that can't be compared are
+/// considered equal.
+pub fn compare_rows<Row: CompareFields>(a: &Row, b: &Row, columns: &[(&str, Order)]) -> Ordering {
+    columns.iter().fold(Ordering::Equal, |ordering, (column, order)| {
+        ordering.then_with(|| {
+            let ordering = a.compare_field(b, column).unwrap_or(Ordering::Equal);
+            match order {
+                Order::Ascending => ordering,
+                Order::Descending => ordering.reverse(),
+            }
+        })
+    })
+}
+
+/// Whether the rows of a table are known to be sorted on their uid, see
+/// [`IdTable::sort`](crate::IdTable::sort). Used by the `table` macro.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UidSorted(pub bool);
+
+/// The flag only caches information about the rows, so two flags are always equal. This way,
+/// tables can still derive `PartialEq` and `Hash`.
+impl PartialEq for UidSorted {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for UidSorted {}
+
+impl Hash for UidSorted {
+    fn hash<H: Hasher>(&self, _: &mut H) {}
+}
@@ -0,0 +1,183 @@
+//! Border styles used when rendering a [`Table`](crate::Table) to a string.
+
+/// A horizontal line in a table, e.g. the top border or the line between the header and the rows.
+///
+/// A line is made up of a `fill` character that is repeated for the width of a column, a
+/// `junction` character where the line crosses a column separator and the `left` and `right`
+/// characters at the outer borders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HorizontalLine {
+    pub left: char,
+    pub fill: char,
+    pub junction: char,
+    pub right: char,
+}
+
+impl HorizontalLine {
+    /// Creates a new horizontal line
+    pub const fn new(left: char, fill: char, junction: char, right: char) -> Self {
+        HorizontalLine { left, fill, junction, right }
+    }
+}
+
+/// Defines how the borders of a table look when it is rendered.
+///
+/// A couple of presets are available as associated constants. The default style, used by
+/// `to_string()`, is [`TableStyle::ASCII`].
+///
+/// # Example
+/// ```rust
+/// # use simple_tables::Table;
+/// # use simple_tables::macros::{table_row, table};
+/// use simple_tables::style::TableStyle;
+///
+/// #[table_row]
+/// #[derive(Clone)]
+/// struct MyTableRow {
+///     id: u32,
+///     name: String
+/// }
+///
+/// #[table(rows = MyTableRow)]
+/// struct MyTable {}
+///
+/// let table = MyTable::from_vec(&vec![MyTableRow { id: 1, name: "Opeth".to_string() }]);
+/// let s = table.render_with(&TableStyle::ROUNDED);
+/// assert_eq!(s, "\
+/// ╭────┬───────╮
+/// │ id │ name  │
+/// ├────┼───────┤
+/// │  1 │ Opeth │
+/// ╰────┴───────╯");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableStyle {
+    /// The line above the header
+    pub top: Option<HorizontalLine>,
+    /// The line between the header and the first row
+    pub header_separator: Option<HorizontalLine>,
+    /// The line between two rows
+    pub row_separator: Option<HorizontalLine>,
+    /// The line below the last row
+    pub bottom: Option<HorizontalLine>,
+    /// The left border. When there is no left border, the padding on the left is omitted as well.
+    pub left: Option<char>,
+    /// The character between two columns
+    pub column_separator: Option<char>,
+    /// The right border. When there is no right border, trailing whitespace is trimmed.
+    pub right: Option<char>,
+    /// The amount of spaces between a cell's content and its borders
+    pub padding: usize,
+}
+
+impl TableStyle {
+    /// The default style
+    /// ```bash
+    /// +----+-------+
+    /// | id | name  |
+    /// +====+=======+
+    /// |  1 | Opeth |
+    /// +----+-------+
+    /// ```
+    pub const ASCII: TableStyle = TableStyle {
+        top: Some(HorizontalLine::new('+', '-', '+', '+')),
+        header_separator: Some(HorizontalLine::new('+', '=', '+', '+')),
+        row_separator: Some(HorizontalLine::new('+', '-', '+', '+')),
+        bottom: Some(HorizontalLine::new('+', '-', '+', '+')),
+        left: Some('|'),
+        column_separator: Some('|'),
+        right: Some('|'),
+        padding: 1,
+    };
+
+    /// Unicode box-drawing characters
+    /// ```bash
+    /// ┌────┬───────┐
+    /// │ id │ name  │
+    /// ├────┼───────┤
+    /// │  1 │ Opeth │
+    /// └────┴───────┘
+    /// ```
+    pub const UNICODE: TableStyle = TableStyle {
+        top: Some(HorizontalLine::new('┌', '─', '┬', '┐')),
+        header_separator: Some(HorizontalLine::new('├', '─', '┼', '┤')),
+        row_separator: Some(HorizontalLine::new('├', '─', '┼', '┤')),
+        bottom: Some(HorizontalLine::new('└', '─', '┴', '┘')),
+        left: Some('│'),
+        column_separator: Some('│'),
+        right: Some('│'),
+        padding: 1,
+    };
+
+    /// Unicode box-drawing characters with rounded corners
+    /// ```bash
+    /// ╭────┬───────╮
+    /// │ id │ name  │
+    /// ├────┼───────┤
+    /// │  1 │ Opeth │
+    /// ╰────┴───────╯
+    /// ```
+    pub const ROUNDED: TableStyle = TableStyle {
+        top: Some(HorizontalLine::new('╭', '─', '┬', '╮')),
+        bottom: Some(HorizontalLine::new('╰', '─', '┴', '╯')),
+        ..TableStyle::UNICODE
+    };
+
+    /// Unicode double-line box-drawing characters
+    /// ```bash
+    /// ╔════╦═══════╗
+    /// ║ id ║ name  ║
+    /// ╠════╬═══════╣
+    /// ║  1 ║ Opeth ║
+    /// ╚════╩═══════╝
+    /// ```
+    pub const DOUBLE: TableStyle = TableStyle {
+        top: Some(HorizontalLine::new('╔', '═', '╦', '╗')),
+        header_separator: Some(HorizontalLine::new('╠', '═', '╬', '╣')),
+        row_separator: Some(HorizontalLine::new('╠', '═', '╬', '╣')),
+        bottom: Some(HorizontalLine::new('╚', '═', '╩', '╝')),
+        left: Some('║'),
+        column_separator: Some('║'),
+        right: Some('║'),
+        padding: 1,
+    };
+
+    /// No borders at all, columns are only separated by whitespace
+    /// ```bash
+    /// id  name
+    ///  1  Opeth
+    /// ```
+    pub const BORDERLESS: TableStyle = TableStyle {
+        top: None,
+        header_separator: None,
+        row_separator: None,
+        bottom: None,
+        left: None,
+        column_separator: None,
+        right: None,
+        padding: 1,
+    };
+
+    /// Mimics the output of PostgreSQL's `psql`
+    /// ```bash
+    /// id | name
+    /// ---+------
+    ///  1 | Opeth
+    /// ```
+    pub const PSQL: TableStyle = TableStyle {
+        top: None,
+        header_separator: Some(HorizontalLine::new('-', '-', '+', '-')),
+        row_separator: None,
+        bottom: None,
+        left: None,
+        column_separator: Some('|'),
+        right: None,
+        padding: 1,
+    };
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        TableStyle::ASCII
+    }
+}
@@ -14,88 +14,1103 @@ use quote::ToTokens;
 
 /// Initialises a struct to be used as a TableRow so it can be used as an entry inside of a
 /// [Table](simple_tables_core::Table)
+///
+/// # Column attributes
+/// Fields can be configured using the `#[column(...)]` attribute:
+/// - `align = "left" | "right" | "center"`: the alignment of the column when rendered
+/// - `rename = "Display Name"`: the name of the column, used in headers and
+///   [`get_fields`](simple_tables_core::TableRow::get_fields), instead of the name of the field.
+///   Every column needs a unique name.
+/// - `skip`: the field is not a column of the table, so it isn't rendered, exported or
+///   serialized. When importing, it is set to its default value.
+/// - `format = "{:.2}"`: the format string used to convert the field to a string
+/// - `debug`: the field is converted to a string using its `Debug` implementation, for types
+///   that don't implement `Display`, like `Vec<T>`
+/// - `with = path::to::fn`: the function used to convert the field to a string, which takes a
+///   reference to the field and returns a `String`. Without `parse_with`, rows with this column
+///   can't be imported.
+/// - `parse_with = path::to::fn`: the function used to parse the field when importing, which
+///   takes a `&str` and returns a `Result` of the field type and an error that implements
+///   `Display`, e.g. the counterpart of `with`
+/// - `null = "-"`: the string used for `None` when the field is an `Option`, can't be combined with
+///   `with` or `parse_with`
+/// - `default`: when importing, the field is set to its default value when its column is empty
+///   or missing
+/// - `flatten`: the type of the field is a table row itself, of which the columns are added to
+///   this row, e.g. `address.street` for the column `street` of the field `address`. Can be
+///   combined with `rename` to change the prefix. Not supported in enums.
+///
+/// ```rust
+/// #[table_row]
+/// struct Product {
+///     #[column(rename = "Product name")]
+///     name: String,
+///     #[column(format = "{:.2}", default)]
+///     price: f64,
+///     #[column(skip)]
+///     internal_id: u64,
+/// }
+/// ```
+///
+/// # Enums
+/// Enums of which the variants have named fields, or no fields, can be used as rows as well. The
+/// first column, `kind`, contains the name of the variant, followed by the columns of all
+/// variants. Fields with the same column name in different variants share a column and must have
+/// the same type. A column is empty for variants that don't have it. Fields that all variants
+/// have can be used as the `uid` of a table. Tables of enum rows can't be serialized as a map of
+/// columns.
+///
+/// ```rust
+/// #[table_row]
+/// enum Event {
+///     Login { id: u32, user: String },
+///     Error { id: u32, message: String },
+///     Restart,
+/// }
+/// ```
+///
+/// The macro doesn't derive any traits for the struct, so add `#[derive(Clone)]` yourself to use
+/// functions that copy rows, like [`Table::from_vec`](simple_tables_core::Table::from_vec).
+///
+/// # Attributes
+/// - `serde`: derives `Serialize` and `Deserialize` for the row, so `#[serde(...)]` attributes
+///   can be used on the struct and its fields, and allows tables of this row to be serialized as
+///   a map of columns. Requires the `serde` feature.
+/// - `null = "-"`: the string used for `None` in all `Option` fields, which is empty by default.
+///   When importing, an empty field or this string is parsed as `None`.
+/// - `columns = ["x", "y"]`: the names of the fields of a tuple struct, which are `0`, `1`, ... by
+///   default. These names are used as column names and for the `uid` of a table.
+///
+/// ```rust
+/// #[table_row(columns = ["x", "y"])]
+/// struct Point(f64, f64);
+/// ```
 #[proc_macro_attribute]
-pub fn table_row(_attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let item_struct = parse_macro_input!(input as ItemStruct);
-    
-    let struct_name = &item_struct.ident;
-    
-    let fields: Vec<(String, syn::Type)>;
-    let mut ident_fields: Vec<(Ident2, syn::Type)> = Vec::new();
-    if let syn::Fields::Named(ref _fields) = item_struct.fields {
-        let _fields = &_fields.named;
-        fields = _fields.iter().map(|field| {
-            if let Some(ident) = &field.ident {
-                let field_name: String = ident.to_string();
-                let field_type = &field.ty;
-                let entry = (field_name, field_type.clone());
-                
-                ident_fields.push((ident.clone(), field_type.clone()));
-                
-                entry
-            } else {
-                panic!("Only named fields are supported.")
-                // syn::Error::into_compile_error("Only named fields are supported."); // TODO
-            }
-        }).collect();
-    } else {
-        panic!("The row struct has no fields.");
+pub fn table_row(attrs: TokenStream, input: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attrs as TableRowArgs);
+    let expanded = match parse_macro_input!(input as syn::Item) {
+        syn::Item::Struct(item_struct) => expand_table_row(args, item_struct),
+        syn::Item::Enum(item_enum) => expand_enum_table_row(args, item_enum),
+        item => Err(syn::Error::new_spanned(item, "Only structs and enums can be used as table rows"))
+    };
+    expanded
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_table_row(args: TableRowArgs, mut item_struct: ItemStruct) -> syn::Result<TokenStream2> {
+    let krate = crate_path();
+    let derive_serde = args.serde.is_some();
+    if matches!(item_struct.fields, syn::Fields::Unit) {
+        return Err(syn::Error::new_spanned(&item_struct, "The row struct has no fields, add named fields like `struct MyTableRow { id: u32 }` or use a tuple struct like `struct MyTableRow(u32)`"));
     }
     
+    // Fields of tuple structs are named by their index, unless they are named using `columns`
+    if let Some((key, columns)) = &args.columns {
+        match &item_struct.fields {
+            syn::Fields::Named(_) => return Err(syn::Error::new(key.span(), "`columns` can only be used on tuple structs, use `#[column(rename = \"...\")]` to rename the columns of named fields")),
+            fields if fields.len() != columns.len() => return Err(syn::Error::new(key.span(), format!("Expected {} column names, one for each field, found {}", fields.len(), columns.len()))),
+            _ => {}
+        }
+    }
+    let mut field_idents: Vec<syn::Member> = Vec::new();
     let mut field_names: Vec<String> = Vec::new();
     let mut field_types: Vec<syn::Type> = Vec::new();
+    let mut column_attrs: Vec<ColumnAttrs> = Vec::new();
+    // Where to report a column name that is already used
+    let mut name_spans: Vec<proc_macro2::Span> = Vec::new();
+    let mut serde_attrs: Vec<SerdeFieldAttrs> = Vec::new();
+    for (i, field) in item_struct.fields.iter_mut().enumerate() {
+        // The `column` attributes are not real attributes, so they are removed from the struct
+        let attrs = ColumnAttrs::take_from(&mut field.attrs, &field.ty)?;
+        if attrs.skip && derive_serde {
+            // Skipped fields aren't serialized as part of a row either, like in a map of columns
+            field.attrs.push(syn::parse_quote!(#[serde(skip)]));
+        }
+        match &field.ident {
+            Some(ident) => {
+                field_idents.push(syn::Member::Named(ident.clone()));
+                field_names.push(ident.to_string());
+                name_spans.push(ident.span());
+            },
+            None => {
+                field_idents.push(syn::Member::Unnamed(syn::Index::from(i)));
+                match &args.columns {
+                    Some((_, columns)) => {
+                        field_names.push(columns[i].value());
+                        name_spans.push(columns[i].span());
+                    },
+                    None => {
+                        field_names.push(i.to_string());
+                        name_spans.push(syn::spanned::Spanned::span(&field.ty));
+                    }
+                }
+            }
+        }
+        if let Some(rename) = &attrs.rename {
+            *name_spans.last_mut().unwrap() = rename.span();
+        }
+        column_attrs.push(attrs);
+        serde_attrs.push(SerdeFieldAttrs::parse(&field.attrs));
+        field_types.push(field.ty.clone());
+    }
     
-    // (field_names, field_types) = fields.iter().unzip(); // TODO
-    fields.iter()
-        .for_each(|field| {
-        let (_name, _type) = field;
-        field_names.push(_name.to_owned());
-        field_types.push(_type.to_owned());
-    });
-    let field_types_strings: Vec<String> = field_types.iter().map(|v| v.to_token_stream().to_string()).collect();
+    let struct_name = &item_struct.ident;
+    let (impl_generics, ty_generics, where_clause) = item_struct.generics.split_for_impl();
+    let predicates = where_predicates(where_clause);
+    let null = args.null();
     
-    let field_len = fields.iter().count();
-    let mut get_field_str_elements: Vec<proc_macro2::TokenStream> = Vec::new();
-    for ident_field in ident_fields {
-        let ident = ident_field.0;
-        let field = quote!( self.#ident );
-        get_field_str_elements.push(field);
+    // The fields that are columns of the table, skipped fields aren't
+    let mut column_names: Vec<String> = Vec::new();
+    let mut column_idents: Vec<&syn::Member> = Vec::new();
+    let mut column_types: Vec<&syn::Type> = Vec::new();
+    let mut parsed_types: Vec<&syn::Type> = Vec::new();
+    let mut column_types_strings: Vec<String> = Vec::new();
+    let mut column_alignments: Vec<TokenStream2> = Vec::new();
+    let mut get_field_str_elements: Vec<TokenStream2> = Vec::new();
+    let mut optional_columns: Vec<String> = Vec::new();
+    let mut column_parsers: Vec<TokenStream2> = Vec::new();
+    let mut skipped_idents: Vec<&syn::Member> = Vec::new();
+    let mut skipped_types: Vec<&syn::Type> = Vec::new();
+    // Fields of which the columns are added to the columns of this row, with their name as prefix
+    let mut flattened_prefixes: Vec<String> = Vec::new();
+    let mut flattened_idents: Vec<&syn::Member> = Vec::new();
+    let mut flattened_types: Vec<&syn::Type> = Vec::new();
+    let mut flattened_parsers: Vec<TokenStream2> = Vec::new();
+    // The statements that build the result of the `TableRow` functions when fields are flattened,
+    // so the columns stay in the order of the fields
+    let mut fields_statements: Vec<TokenStream2> = Vec::new();
+    let mut types_statements: Vec<TokenStream2> = Vec::new();
+    let mut field_str_statements: Vec<TokenStream2> = Vec::new();
+    let mut alignments_statements: Vec<TokenStream2> = Vec::new();
+    // Serialized as a map of columns, flattened fields are a column of nested values. The keys are
+    // the names of the columns, unless they are renamed using `#[serde(rename = "...")]`.
+    let mut serde_keys: Vec<(String, String)> = Vec::new();
+    let mut serde_idents: Vec<&syn::Member> = Vec::new();
+    let mut serde_types: Vec<&syn::Type> = Vec::new();
+    let mut serde_skipped_idents: Vec<&syn::Member> = Vec::new();
+    let mut serde_skipped_types: Vec<&syn::Type> = Vec::new();
+    for (((((ident, field_name), ty), attrs), span), serde_attrs) in field_idents.iter().zip(field_names.iter()).zip(field_types.iter()).zip(column_attrs.iter()).zip(name_spans.iter()).zip(serde_attrs.iter()) {
+        if attrs.skip {
+            skipped_idents.push(ident);
+            skipped_types.push(ty);
+            serde_skipped_idents.push(ident);
+            serde_skipped_types.push(ty);
+            continue;
+        }
+        let name = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => field_name.clone()
+        };
+        check_column_name(&name, attrs.flatten.is_some(), &column_names, &flattened_prefixes, *span)?;
+        if serde_attrs.skip {
+            serde_skipped_idents.push(ident);
+            serde_skipped_types.push(ty);
+        } else {
+            serde_keys.push((
+                serde_attrs.serialize_name.clone().unwrap_or_else(|| name.clone()),
+                serde_attrs.deserialize_name.clone().unwrap_or_else(|| name.clone()),
+            ));
+            serde_idents.push(ident);
+            serde_types.push(ty);
+        }
+        // The position of the first column of this field in the input of `parse_fields`
+        let position = column_names.len();
+        let position = quote!(#position #(+ <#flattened_types as #krate::core::TableRow>::field_count())*);
+        if attrs.flatten.is_some() {
+            let prefix = &name;
+            fields_statements.push(quote!(fields.extend(#krate::core::prefixed_fields(#prefix, <#ty as #krate::core::TableRow>::get_fields()));));
+            types_statements.push(quote!(types.extend(<#ty as #krate::core::TableRow>::get_field_types());));
+            field_str_statements.push(quote!(values.extend(#krate::core::TableRow::get_field_str(&self.#ident));));
+            alignments_statements.push(quote!(alignments.extend(<#ty as #krate::core::TableRow>::get_field_alignments());));
+            flattened_parsers.push(quote!(
+                <#ty as #krate::core::ParseTableRow>::parse_fields(&fields[#position..#position + <#ty as #krate::core::TableRow>::field_count()])
+                    .map_err(|(field, err)| (#krate::core::prefixed_field(#prefix, field), err))?
+            ));
+            flattened_prefixes.push(name);
+            flattened_idents.push(ident);
+            flattened_types.push(ty);
+            continue;
+        }
+        column_parsers.push(field_parser(quote!(fields[#position]), ty, attrs, &name, &null));
+        let type_string = ty.to_token_stream().to_string();
+        column_alignments.push(column_alignment(&krate, ty, attrs));
+        get_field_str_elements.push(field_to_string(quote!(&self.#ident), ty, attrs, &null));
+        let (field_str, alignment) = (get_field_str_elements.last(), column_alignments.last());
+        fields_statements.push(quote!(fields.push(#name);));
+        types_statements.push(quote!(types.push(#type_string);));
+        field_str_statements.push(quote!(values.push(#field_str);));
+        alignments_statements.push(quote!(alignments.push(#alignment);));
+        if attrs.default || option_inner(ty).is_some() {
+            optional_columns.push(name.clone());
+        }
+        column_names.push(name);
+        column_idents.push(ident);
+        column_types.push(ty);
+        if attrs.parse_with.is_none() {
+            parsed_types.push(parsed_type(ty));
+        }
+        column_types_strings.push(type_string);
     }
+    let column_len = column_names.len();
+    
+    // The columns of flattened fields are only known when the row is used, so the columns are
+    // collected at runtime and there are no `FIELDS` and `TYPES` constants
+    let flattened = !flattened_idents.is_empty();
+    let constants = if flattened {
+        quote!()
+    } else {
+        quote!(
+            const FIELDS: [&'static str; #column_len] = [#(#column_names),*];
+            // const TYPES: [FieldType; #column_len] = [#(#column_types),*];
+            const TYPES: [&'static str; #column_len] = [#(#column_types_strings),*];
+        )
+    };
+    let (get_fields, get_field_types, get_field_str, get_field_alignments) = if flattened {
+        (
+            quote!(let mut fields = ::std::vec::Vec::new(); #(#fields_statements)* fields),
+            quote!(let mut types = ::std::vec::Vec::new(); #(#types_statements)* types),
+            quote!(let mut values = ::std::vec::Vec::new(); #(#field_str_statements)* values),
+            quote!(let mut alignments = ::std::vec::Vec::new(); #(#alignments_statements)* alignments),
+        )
+    } else {
+        (
+            quote!(Self::FIELDS.to_vec()),
+            quote!(Self::TYPES.to_vec()),
+            quote!(::std::vec![ #(#get_field_str_elements,)* ]),
+            quote!(::std::vec![#(#column_alignments),*]),
+        )
+    };
     let get_field_str = quote!(
-        fn get_field_str(&self) -> Vec<String> {
-            vec![ #(#get_field_str_elements.to_string(),)* ]
+        fn get_field_str(&self) -> ::std::vec::Vec<::std::string::String> {
+            #get_field_str
         }
     );
-    TokenStream::from (
-        quote! (
-            use simple_tables::core::TableRow as TableRowTrait;
+    // Parsing rows from strings is only possible when all columns implement `FromStr` and all
+    // skipped fields implement `Default`. The bounds are higher-ranked so that they are not
+    // checked when the struct is defined, the impl just doesn't apply when they aren't met.
+    let flattened_prefixes_iter = flattened_prefixes.iter();
+    let impl_parse_table_row = quote!(
+        impl #impl_generics #krate::core::ParseTableRow for #struct_name #ty_generics
+            where #predicates
+                  #(for<'__a> #parsed_types: ::std::str::FromStr,
+                    for<'__a> <#parsed_types as ::std::str::FromStr>::Err: ::std::fmt::Display,)*
+                  #(for<'__a> #flattened_types: #krate::core::ParseTableRow,)*
+                  #(for<'__a> #skipped_types: ::std::default::Default,)*
+        {
+            fn parse_fields(fields: &[&str]) -> ::std::result::Result<Self, (&'static str, ::std::string::String)> {
+                ::std::result::Result::Ok(Self {
+                    #(#column_idents: #column_parsers,)*
+                    #(#flattened_idents: #flattened_parsers,)*
+                    #(#skipped_idents: ::std::default::Default::default(),)*
+                })
+            }
             
-            #[derive(Debug, Clone)]
+            fn get_optional_fields() -> ::std::vec::Vec<&'static str> {
+                #[allow(unused_mut)]
+                let mut fields = ::std::vec![#(#optional_columns),*];
+                #(fields.extend(#krate::core::prefixed_fields(#flattened_prefixes_iter, <#flattened_types as #krate::core::ParseTableRow>::get_optional_fields()));)*
+                fields
+            }
+        }
+    );
+    // Columns converted to a string using `with` can't be parsed back without `parse_with`
+    let impl_parse_table_row = if importable(column_attrs.iter()) { impl_parse_table_row } else { quote!() };
+    
+    // Like `ParseTableRow`, comparing fields is only possible when all columns implement `PartialOrd`
+    let flattened_prefixes_dot: Vec<String> = flattened_prefixes.iter().map(|prefix| format!("{}.", prefix)).collect();
+    let impl_compare_fields = quote!(
+        impl #impl_generics #krate::core::sort::CompareFields for #struct_name #ty_generics
+            where #predicates
+                  #(for<'__a> #column_types: ::std::cmp::PartialOrd,)*
+                  #(for<'__a> #flattened_types: #krate::core::sort::CompareFields,)*
+        {
+            fn compare_field(&self, other: &Self, field: &str) -> ::std::option::Option<::std::cmp::Ordering> {
+                match field {
+                    #(#column_names => self.#column_idents.partial_cmp(&other.#column_idents),)*
+                    #(field if field.starts_with(#flattened_prefixes_dot) => #krate::core::sort::CompareFields::compare_field(
+                        &self.#flattened_idents, &other.#flattened_idents, &field[#flattened_prefixes_dot.len()..]
+                    ),)*
+                    _ => ::std::option::Option::None
+                }
+            }
+        }
+    );
+    
+    // Every field can be looked up by its name, which is used by the `table` macro to find the
+    // type of the uid field
+    let field_name_types: Vec<TokenStream2> = field_names.iter().map(|name| field_name_type(name)).collect();
+    let impl_row_fields = quote!(
+        #(
+            // Not listed when the uid isn't a field, their encoded names aren't readable
+            #[diagnostic::do_not_recommend]
+            impl #impl_generics #krate::core::RowField<#field_name_types> for #struct_name #ty_generics #where_clause {
+                type Type = #field_types;
+                
+                fn get_field(&self) -> &#field_types {
+                    &self.#field_idents
+                }
+            }
+        )*
+    );
+    
+    let serde_code = if derive_serde {
+        serde_row_code(struct_name, &item_struct.generics, &serde_keys, &serde_idents, &serde_types, &serde_skipped_idents, &serde_skipped_types)
+    } else {
+        quote!()
+    };
+    let serde_derive = if derive_serde {
+        let serde_crate = serde_crate_path(&krate);
+        quote!(
+            #[derive(#krate::core::serde::Serialize, #krate::core::serde::Deserialize)]
+            #[serde(crate = #serde_crate)]
+        )
+    } else {
+        quote!()
+    };
+    
+    Ok(
+        quote! (
+            #serde_derive
             #item_struct
             
-            impl #struct_name {
-                const FIELDS: [&'static str; #field_len] = [#(#field_names),*];
-                // const TYPES: [FieldType; #field_len] = [#(#field_types),*];
-                const TYPES: [&'static str; #field_len] = [#(#field_types_strings),*];
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #constants
                 
                 #get_field_str
             }
             
-            impl TableRowTrait for #struct_name {
-                fn get_fields() -> Vec<&'static str> {
+            impl #impl_generics #krate::core::TableRow for #struct_name #ty_generics #where_clause {
+                fn get_fields() -> ::std::vec::Vec<&'static str> {
+                    #get_fields
+                }
+                fn get_field_types() -> ::std::vec::Vec<&'static str> {
+                    #get_field_types
+                }
+                fn get_field_str(&self) -> ::std::vec::Vec<::std::string::String> {
+                    Self::get_field_str(self)
+                }
+                fn get_field_alignments() -> ::std::vec::Vec<#krate::core::render::Alignment> {
+                    #get_field_alignments
+                }
+                fn field_count() -> usize {
+                    #column_len #(+ <#flattened_types as #krate::core::TableRow>::field_count())*
+                }
+            }
+            
+            #impl_parse_table_row
+            
+            #impl_compare_fields
+            
+            #impl_row_fields
+            
+            #serde_code
+        )
+    )
+}
+
+/// A field of an enum variant, with its `column` attributes
+type VariantField = (Ident2, syn::Type, ColumnAttrs);
+
+/// A column of an enum row, which is the union of the fields with this column name in all variants
+struct EnumColumn<'a> {
+    name: String,
+    ty: &'a syn::Type,
+    align: Option<&'a Ident2>,
+}
+
+/// Like `expand_table_row`, but for an enum of which the variants have named fields or no fields.
+/// The first column, `kind`, contains the name of the variant, followed by the columns of all
+/// variants. A column is empty for variants that don't have it.
+fn expand_enum_table_row(args: TableRowArgs, mut item_enum: syn::ItemEnum) -> syn::Result<TokenStream2> {
+    let krate = crate_path();
+    if let Some((key, _)) = &args.columns {
+        return Err(syn::Error::new(key.span(), "`columns` can only be used on tuple structs, use `#[column(rename = \"...\")]` to rename the columns of named fields"));
+    }
+    if item_enum.variants.is_empty() {
+        return Err(syn::Error::new_spanned(&item_enum, "Enums without variants can't be used as table rows"));
+    }
+    let derive_serde = args.serde.is_some();
+    
+    // The fields of each variant, with their attributes
+    let mut variant_fields: Vec<Vec<VariantField>> = Vec::new();
+    for variant in item_enum.variants.iter_mut() {
+        let mut fields = Vec::new();
+        // The columns of this variant, other variants can have columns with the same name
+        let mut column_names: Vec<String> = Vec::new();
+        match &mut variant.fields {
+            syn::Fields::Named(named) => for field in named.named.iter_mut() {
+                // Named fields always have an ident
+                let ident = field.ident.clone().unwrap();
+                let attrs = ColumnAttrs::take_from(&mut field.attrs, &field.ty)?;
+                if attrs.skip && derive_serde {
+                    field.attrs.push(syn::parse_quote!(#[serde(skip)]));
+                }
+                if !attrs.skip {
+                    match &attrs.rename {
+                        Some(rename) if rename.value() == "kind" => {
+                            return Err(syn::Error::new(rename.span(), "The `kind` column of an enum row contains the name of the variant, use another name for this column"));
+                        },
+                        None if ident == "kind" => {
+                            return Err(syn::Error::new(ident.span(), "The `kind` column of an enum row contains the name of the variant, rename this field using `#[column(rename = \"...\")]`"));
+                        },
+                        _ => {}
+                    }
+                }
+                if let Some(flatten) = &attrs.flatten {
+                    return Err(syn::Error::new_spanned(flatten, "`flatten` is not supported in enum rows"));
+                }
+                if !attrs.skip {
+                    let (name, span) = match &attrs.rename {
+                        Some(rename) => (rename.value(), rename.span()),
+                        None => (ident.to_string(), ident.span())
+                    };
+                    check_column_name(&name, false, &column_names, &[], span)?;
+                    column_names.push(name);
+                }
+                fields.push((ident, field.ty.clone(), attrs));
+            },
+            syn::Fields::Unit => {},
+            fields => return Err(syn::Error::new_spanned(fields, "Only variants with named fields or without fields are supported in table rows"))
+        }
+        variant_fields.push(fields);
+    }
+    let variant_idents: Vec<&Ident2> = item_enum.variants.iter().map(|variant| &variant.ident).collect();
+    let variant_names: Vec<String> = variant_idents.iter().map(|ident| ident.to_string()).collect();
+    
+    // The union of the columns of all variants, in the order in which they first occur
+    let mut columns: Vec<EnumColumn> = Vec::new();
+    for (ident, ty, attrs) in variant_fields.iter().flatten().filter(|(_, _, attrs)| !attrs.skip) {
+        let name = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => ident.to_string()
+        };
+        match columns.iter_mut().find(|column| column.name == name) {
+            Some(column) if column.ty.to_token_stream().to_string() != ty.to_token_stream().to_string() => {
+                return Err(syn::Error::new_spanned(ty, format!("The column `{}` has type `{}` in another variant, columns with the same name must have the same type", name, column.ty.to_token_stream())));
+            },
+            Some(column) => if column.align.is_none() {
+                column.align = attrs.align.as_ref();
+            },
+            None => columns.push(EnumColumn { name, ty, align: attrs.align.as_ref() })
+        }
+    }
+    let column_len = columns.len() + 1;
+    let column_names: Vec<&String> = columns.iter().map(|column| &column.name).collect();
+    let column_types: Vec<&syn::Type> = columns.iter().map(|column| column.ty).collect();
+    let column_types_strings: Vec<String> = column_types.iter().map(|ty| ty.to_token_stream().to_string()).collect();
+    let column_alignments: Vec<TokenStream2> = columns.iter().map(|column| match column.align {
+        Some(align) => quote!(#krate::core::render::Alignment::#align),
+        None => {
+            let ty = parsed_type(column.ty);
+            quote!(#krate::core::render::Alignment::for_type(::std::any::type_name::<#ty>()))
+        }
+    }).collect();
+    let null = args.null();
+    let skipped_types: Vec<&syn::Type> = variant_fields.iter().flatten()
+        .filter(|(_, _, attrs)| attrs.skip)
+        .map(|(_, ty, _)| ty)
+        .collect();
+    
+    // For each variant, the field in each column, if the variant has this column
+    let column_fields: Vec<Vec<Option<&VariantField>>> = variant_fields.iter().map(|fields| {
+        columns.iter().map(|column| fields.iter().find(|(ident, _, attrs)| {
+            !attrs.skip && attrs.rename.as_ref().map(syn::LitStr::value).unwrap_or_else(|| ident.to_string()) == column.name
+        })).collect()
+    }).collect();
+    
+    // Columns of which every field is parsed using `parse_with` don't have to implement `FromStr`
+    let parsed_types: Vec<&syn::Type> = columns.iter().enumerate()
+        .filter(|(i, _)| column_fields.iter().any(|fields| matches!(fields[*i], Some((_, _, attrs)) if attrs.parse_with.is_none())))
+        .map(|(_, column)| parsed_type(column.ty))
+        .collect();
+    
+    let get_field_str_arms: Vec<TokenStream2> = variant_idents.iter().zip(column_fields.iter()).map(|(variant, fields)| {
+        let bindings = fields.iter().flatten().map(|(ident, _, _)| ident);
+        let values = fields.iter().map(|field| match field {
+            Some((ident, ty, attrs)) => field_to_string(quote!(#ident), ty, attrs, &null),
+            None => quote!(::std::string::String::new())
+        });
+        let name = variant.to_string();
+        quote!(Self::#variant { #(#bindings,)* .. } => ::std::vec![#name.to_string(), #(#values,)*])
+    }).collect();
+    
+    // A column is optional when it's missing from a variant, or when its field has a default or
+    // is an `Option`
+    let optional_columns: Vec<&String> = columns.iter().enumerate()
+        .filter(|(i, column)| option_inner(column.ty).is_some() || column_fields.iter().any(|fields| match fields[*i] {
+            Some((_, _, attrs)) => attrs.default,
+            None => true
+        }))
+        .map(|(_, column)| &column.name)
+        .collect();
+    let parse_arms: Vec<TokenStream2> = variant_idents.iter().zip(variant_fields.iter()).zip(column_fields.iter()).map(|((variant, fields), column_fields)| {
+        let name = variant.to_string();
+        let values = fields.iter().map(|(ident, ty, attrs)| {
+            let column = column_fields.iter().position(|field| matches!(field, Some((field_ident, _, _)) if field_ident == ident));
+            match column {
+                Some(i) => {
+                    let i = i + 1;
+                    let parse = field_parser(quote!(fields[#i]), ty, attrs, &columns[i - 1].name, &null);
+                    quote!(#ident: #parse)
+                },
+                None => quote!(#ident: ::std::default::Default::default())
+            }
+        });
+        quote!(#name => Self::#variant { #(#values,)* })
+    }).collect();
+    let expected_kinds = variant_names.iter().map(|name| format!("`{}`", name)).collect::<Vec<String>>().join(", ");
+    
+    let compare_columns: Vec<TokenStream2> = columns.iter().enumerate().map(|(i, column)| {
+        let arms: Vec<TokenStream2> = variant_idents.iter().zip(column_fields.iter()).filter_map(|(variant, fields)| {
+            fields[i].map(|(ident, _, _)| quote!(Self::#variant { #ident: value, .. } => ::std::option::Option::Some(value),))
+        }).collect();
+        let name = &column.name;
+        // Rows without this column are `None`, so they come first
+        quote!(#name => {
+            #[allow(unreachable_patterns)]
+            let value = match self {
+                #(#arms)*
+                _ => ::std::option::Option::None
+            };
+            #[allow(unreachable_patterns)]
+            let other_value = match other {
+                #(#arms)*
+                _ => ::std::option::Option::None
+            };
+            value.partial_cmp(&other_value)
+        },)
+    }).collect();
+    
+    // Fields that all variants have can be used as the uid of a table
+    let common_fields: Vec<(&Ident2, &syn::Type)> = match variant_fields.split_first() {
+        Some((first, rest)) => first.iter()
+            .filter(|(ident, ty, _)| rest.iter().all(|fields| fields.iter().any(|(other_ident, other_ty, _)| {
+                other_ident == ident && other_ty.to_token_stream().to_string() == ty.to_token_stream().to_string()
+            })))
+            .map(|(ident, ty, _)| (ident, ty))
+            .collect(),
+        None => Vec::new()
+    };
+    let impl_row_fields = common_fields.iter().map(|(ident, ty)| {
+        let field_name_type = field_name_type(&ident.to_string());
+        let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+        let struct_name = &item_enum.ident;
+        quote!(
+            #[diagnostic::do_not_recommend]
+            impl #impl_generics #krate::core::RowField<#field_name_type> for #struct_name #ty_generics #where_clause {
+                type Type = #ty;
+                
+                fn get_field(&self) -> &#ty {
+                    match self {
+                        #(Self::#variant_idents { #ident, .. } => #ident,)*
+                    }
+                }
+            }
+        )
+    });
+    
+    let enum_name = &item_enum.ident;
+    let (impl_generics, ty_generics, where_clause) = item_enum.generics.split_for_impl();
+    let predicates = where_predicates(where_clause);
+    let impl_parse_table_row = if importable(variant_fields.iter().flatten().map(|(_, _, attrs)| attrs)) {
+        quote!(
+            impl #impl_generics #krate::core::ParseTableRow for #enum_name #ty_generics
+                where #predicates
+                      #(for<'__a> #parsed_types: ::std::str::FromStr,
+                        for<'__a> <#parsed_types as ::std::str::FromStr>::Err: ::std::fmt::Display,)*
+                      #(for<'__a> #skipped_types: ::std::default::Default,)*
+            {
+                fn parse_fields(fields: &[&str]) -> ::std::result::Result<Self, (&'static str, ::std::string::String)> {
+                    ::std::result::Result::Ok(match fields[0] {
+                        #(#parse_arms,)*
+                        kind => return ::std::result::Result::Err(("kind", ::std::format!("Unknown kind `{}`, expected one of {}", kind, #expected_kinds)))
+                    })
+                }
+                
+                fn get_optional_fields() -> ::std::vec::Vec<&'static str> {
+                    ::std::vec![#(#optional_columns),*]
+                }
+            }
+        )
+    } else {
+        quote!()
+    };
+    
+    let serde_derive = if derive_serde {
+        let serde_crate = serde_crate_path(&krate);
+        quote!(
+            #[derive(#krate::core::serde::Serialize, #krate::core::serde::Deserialize)]
+            #[serde(crate = #serde_crate)]
+        )
+    } else {
+        quote!()
+    };
+    
+    Ok(
+        quote!(
+            #serde_derive
+            #item_enum
+            
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                const FIELDS: [&'static str; #column_len] = ["kind", #(#column_names),*];
+                const TYPES: [&'static str; #column_len] = ["&str", #(#column_types_strings),*];
+                
+                fn get_field_str(&self) -> ::std::vec::Vec<::std::string::String> {
+                    match self {
+                        #(#get_field_str_arms,)*
+                    }
+                }
+            }
+            
+            impl #impl_generics #krate::core::TableRow for #enum_name #ty_generics #where_clause {
+                fn get_fields() -> ::std::vec::Vec<&'static str> {
                     Self::FIELDS.to_vec()
                 }
-                fn get_field_types() -> Vec<&'static str> {
+                fn get_field_types() -> ::std::vec::Vec<&'static str> {
                     Self::TYPES.to_vec()
                 }
+                fn get_field_str(&self) -> ::std::vec::Vec<::std::string::String> {
+                    Self::get_field_str(self)
+                }
+                fn get_field_alignments() -> ::std::vec::Vec<#krate::core::render::Alignment> {
+                    ::std::vec![#krate::core::render::Alignment::Left, #(#column_alignments),*]
+                }
                 fn field_count() -> usize {
-                    return #field_len;
+                    #column_len
                 }
             }
+            
+            #impl_parse_table_row
+            
+            impl #impl_generics #krate::core::sort::CompareFields for #enum_name #ty_generics
+                where #predicates
+                      #(for<'__a> #column_types: ::std::cmp::PartialOrd,)*
+            {
+                fn compare_field(&self, other: &Self, field: &str) -> ::std::option::Option<::std::cmp::Ordering> {
+                    let kind = |row: &Self| match row {
+                        #(Self::#variant_idents { .. } => #variant_names,)*
+                    };
+                    match field {
+                        "kind" => kind(self).partial_cmp(kind(other)),
+                        #(#compare_columns)*
+                        _ => ::std::option::Option::None
+                    }
+                }
+            }
+            
+            #(#impl_row_fields)*
         )
     )
 }
 
+/// Implements `SerdeColumns` for a row. Skipped fields are not serialized and set to their default
+/// value when deserializing.
+#[cfg(feature = "serde")]
+fn serde_row_code(struct_name: &Ident2, generics: &syn::Generics, field_keys: &[(String, String)], field_idents: &[&syn::Member], field_types: &[&syn::Type], skipped_idents: &[&syn::Member], skipped_types: &[&syn::Type]) -> TokenStream2 {
+    let krate = crate_path();
+    let serde_crate = serde_crate_path(&krate);
+    let field_len = field_keys.len();
+    // The key of each column when serializing and deserializing
+    let (serialize_keys, deserialize_keys): (Vec<&String>, Vec<&String>) = field_keys.iter().map(|(ser, de)| (ser, de)).unzip();
+    // The fields of `Columns`, which also work for rows that are tuple structs
+    let column_idents: Vec<Ident2> = (0..field_len).map(|i| format_ident!("__column{}", i)).collect();
+    let first_field = column_idents.first();
+    let len = match first_field {
+        Some(first_field) => quote!(columns.#first_field.len()),
+        None => quote!(0)
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let predicates = where_predicates(where_clause);
+    // `Columns` has the same generic parameters as the row, which might not all be used by the
+    // columns
+    let markers = generics.params.iter().filter_map(|param| match param {
+        syn::GenericParam::Type(param) => {
+            let ident = &param.ident;
+            Some(quote!(#ident))
+        },
+        syn::GenericParam::Lifetime(param) => {
+            let lifetime = &param.lifetime;
+            Some(quote!(&#lifetime ()))
+        },
+        syn::GenericParam::Const(_) => None
+    });
+    quote!(
+        impl #impl_generics #krate::core::serde_columns::SerdeColumns for #struct_name #ty_generics
+            where #predicates
+                  #(for<'__a> #field_types: #krate::core::serde::Serialize + #krate::core::serde::de::DeserializeOwned,)*
+                  #(for<'__a> #skipped_types: ::std::default::Default,)*
+        {
+            fn serialize_columns<S: #krate::core::serde::Serializer>(rows: &[Self], serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                use #krate::core::serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(::std::option::Option::Some(#field_len))?;
+                #(map.serialize_entry(#serialize_keys, &rows.iter().map(|row| &row.#field_idents).collect::<::std::vec::Vec<&#field_types>>())?;)*
+                map.end()
+            }
+            
+            fn deserialize_columns<'de, D: #krate::core::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<::std::vec::Vec<Self>, D::Error> {
+                #[derive(#krate::core::serde::Deserialize)]
+                #[serde(crate = #serde_crate)]
+                struct Columns #impl_generics #where_clause {
+                    #(#[serde(rename = #deserialize_keys)] #column_idents: ::std::vec::Vec<#field_types>,)*
+                    #[serde(skip)]
+                    #[allow(dead_code)]
+                    __marker: ::std::marker::PhantomData<fn() -> (#(#markers,)*)>,
+                }
+                
+                let columns = <Columns #ty_generics as #krate::core::serde::Deserialize>::deserialize(deserializer)?;
+                let len = #len;
+                #(
+                    if columns.#column_idents.len() != len {
+                        return ::std::result::Result::Err(<D::Error as #krate::core::serde::de::Error>::custom(
+                            ::std::format!("column `{}` has {} values, expected {}", #deserialize_keys, columns.#column_idents.len(), len)
+                        ));
+                    }
+                    let mut #column_idents = columns.#column_idents.into_iter();
+                )*
+                ::std::result::Result::Ok((0..len).map(|_| Self {
+                    #(#field_idents: #column_idents.next().unwrap(),)*
+                    #(#skipped_idents: ::std::default::Default::default(),)*
+                }).collect())
+            }
+        }
+    )
+}
+
+#[cfg(not(feature = "serde"))]
+fn serde_row_code(_: &Ident2, _: &syn::Generics, _: &[(String, String)], _: &[&syn::Member], _: &[&syn::Type], _: &[&syn::Member], _: &[&syn::Type]) -> TokenStream2 {
+    // Using `serde` without the feature is already reported as an error
+    quote!()
+}
+
+/// Returns the path to the `simple_tables` crate, as seen from the crate using the macros. This way
+/// the generated code doesn't depend on any names in scope, and still works when `simple_tables`
+/// is renamed in `Cargo.toml`.
+fn crate_path() -> syn::Path {
+    match proc_macro_crate::crate_name("simple_tables") {
+        Ok(proc_macro_crate::FoundCrate::Name(name)) => {
+            let ident = Ident2::new(&name, proc_macro2::Span::call_site());
+            syn::parse_quote!(::#ident)
+        },
+        // Doc tests of `simple_tables` itself are separate crates that depend on it
+        Ok(proc_macro_crate::FoundCrate::Itself) | Err(_) => syn::parse_quote!(::simple_tables)
+    }
+}
+
+/// Returns the path to the serde re-export as a string, for use in `#[serde(crate = "...")]`
+fn serde_crate_path(krate: &syn::Path) -> String {
+    quote!(#krate::core::serde).to_string().replace(' ', "")
+}
+
+/// Returns the predicates of a where clause, each followed by a comma, so more predicates can be
+/// added after them
+fn where_predicates(where_clause: Option<&syn::WhereClause>) -> TokenStream2 {
+    match where_clause {
+        Some(where_clause) => {
+            let predicates = where_clause.predicates.iter();
+            quote!(#(#predicates,)*)
+        },
+        None => quote!()
+    }
+}
+
+/// Returns `T` if `ty` is `Option<T>`
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let path = match ty {
+        syn::Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return None
+    };
+    let segment = path.segments.last()?;
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if segment.ident == "Option" && args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None
+        },
+        _ => None
+    }
+}
+
+/// Converts a field to a string, given an expression that is a reference to the field. `None` is
+/// converted to `null`.
+/// Returns an error when a column has the same name as a column or flattened field before it. The
+/// columns of flattened fields are only known at runtime, so a column starting with the name of a
+/// flattened field and a `.` is rejected as well.
+fn check_column_name(name: &str, flattened: bool, column_names: &[String], flattened_prefixes: &[String], span: proc_macro2::Span) -> syn::Result<()> {
+    let has_prefix = |name: &str, prefix: &str| name.strip_prefix(prefix).is_some_and(|rest| rest.starts_with('.'));
+    if column_names.iter().chain(flattened_prefixes).any(|other| other == name) {
+        return Err(syn::Error::new(span, format!("There already is a column named `{}`, use `#[column(rename = \"...\")]` to give this field another name", name)));
+    }
+    if flattened {
+        if let Some(other) = column_names.iter().chain(flattened_prefixes).find(|other| has_prefix(other, name) || has_prefix(name, other)) {
+            return Err(syn::Error::new(span, format!("The columns of this flattened field start with `{}.` and could have the same name as `{}`, use `#[column(rename = \"...\")]` to give this field another name", name, other)));
+        }
+    } else if let Some(prefix) = flattened_prefixes.iter().find(|prefix| has_prefix(name, prefix)) {
+        return Err(syn::Error::new(span, format!("The column `{}` could have the same name as a column of the flattened field `{}`, use `#[column(rename = \"...\")]` to give this field another name", name, prefix)));
+    }
+    Ok(())
+}
+
+fn field_to_string(value: TokenStream2, ty: &syn::Type, attrs: &ColumnAttrs, null: &str) -> TokenStream2 {
+    if let Some(with) = &attrs.with {
+        return quote!(#with(#value));
+    }
+    let to_string = |value: TokenStream2| match (&attrs.format, &attrs.debug) {
+        (Some(format), _) => quote!(::std::format!(#format, #value)),
+        (None, Some(_)) => quote!(::std::format!("{:?}", #value)),
+        (None, None) => quote!(::std::string::ToString::to_string(#value))
+    };
+    if option_inner(ty).is_some() {
+        let null = attrs.null.as_ref().map(syn::LitStr::value).unwrap_or_else(|| null.to_string());
+        let some = to_string(quote!(value));
+        quote!(match #value {
+            ::std::option::Option::Some(value) => #some,
+            ::std::option::Option::None => ::std::string::ToString::to_string(#null)
+        })
+    } else {
+        to_string(value)
+    }
+}
+
+/// Parses a field from `input`, an expression of type `&str`. For an `Option`, an empty string or
+/// `null` is `None`, unless the field is parsed using `parse_with`.
+fn field_parser(input: TokenStream2, ty: &syn::Type, attrs: &ColumnAttrs, column_name: &str, null: &str) -> TokenStream2 {
+    let parse = |ty: &syn::Type| quote!(#input.parse::<#ty>().map_err(|err| (#column_name, err.to_string()))?);
+    if let Some(parse_with) = &attrs.parse_with {
+        let parse = quote!(#parse_with(#input).map_err(|err| (#column_name, err.to_string()))?);
+        return if attrs.default {
+            quote!(if #input.is_empty() { ::std::default::Default::default() } else { #parse })
+        } else {
+            parse
+        };
+    }
+    match option_inner(ty) {
+        Some(inner) => {
+            let null = attrs.null.as_ref().map(syn::LitStr::value).unwrap_or_else(|| null.to_string());
+            let parse = parse(inner);
+            let is_null = if null.is_empty() { quote!(#input.is_empty()) } else { quote!(#input.is_empty() || #input == #null) };
+            quote!(if #is_null { ::std::option::Option::None } else { ::std::option::Option::Some(#parse) })
+        },
+        None if attrs.default => {
+            let parse = parse(ty);
+            quote!(if #input.is_empty() { ::std::default::Default::default() } else { #parse })
+        },
+        None => parse(ty)
+    }
+}
+
+/// Whether rows can be parsed from strings, which isn't the case when a column is converted to a
+/// string using `with` but isn't parsed using `parse_with`
+fn importable<'a>(mut column_attrs: impl Iterator<Item = &'a ColumnAttrs>) -> bool {
+    column_attrs.all(|attrs| attrs.skip || attrs.with.is_none() || attrs.parse_with.is_some())
+}
+
+/// Returns the type that is parsed for a field, which is `T` for an `Option<T>`
+fn parsed_type(ty: &syn::Type) -> &syn::Type {
+    option_inner(ty).unwrap_or(ty)
+}
+
+/// Returns the alignment of a column. The name of the type is looked up when the row is used, so
+/// generic types are resolved. `Option`s are aligned like the type they contain.
+fn column_alignment(krate: &syn::Path, ty: &syn::Type, attrs: &ColumnAttrs) -> TokenStream2 {
+    match &attrs.align {
+        Some(align) => quote!(#krate::core::render::Alignment::#align),
+        None => {
+            let ty = parsed_type(ty);
+            quote!(#krate::core::render::Alignment::for_type(::std::any::type_name::<#ty>()))
+        }
+    }
+}
+
+/// Encodes the name of a field as a type, used as the type parameter of `RowField`
+fn field_name_type(name: &str) -> TokenStream2 {
+    let krate = crate_path();
+    let chars = name.chars();
+    quote!( (#(#krate::core::FieldChar<#chars>,)*) )
+}
+
+/// Moves all tokens to `span`, keeping how their names are resolved, so that errors in the
+/// generated code are reported at `span`
+fn located_at(tokens: TokenStream2, span: proc_macro2::Span) -> TokenStream2 {
+    tokens.into_iter().map(|mut token| {
+        if let proc_macro2::TokenTree::Group(group) = &token {
+            let mut new_group = proc_macro2::Group::new(group.delimiter(), located_at(group.stream(), span));
+            new_group.set_span(group.span().located_at(span));
+            token = proc_macro2::TokenTree::Group(new_group);
+        } else {
+            token.set_span(token.span().located_at(span));
+        }
+        token
+    }).collect()
+}
+
+/// The options of a field in a table row, set using the `#[column(...)]` attribute
+#[derive(Default)]
+struct ColumnAttrs {
+    /// The `Alignment` variant to use for this column
+    align: Option<Ident2>,
+    /// The name of the column, instead of the name of the field
+    rename: Option<syn::LitStr>,
+    /// The field is not a column of the table
+    skip: bool,
+    /// The format string used to convert the field to a string, e.g. `"{:.2}"`
+    format: Option<syn::LitStr>,
+    /// The field is set to its default value when it is empty or missing when importing
+    default: bool,
+    /// The field is a row itself, of which the columns are added to the columns of this row
+    flatten: Option<syn::Path>,
+    /// The field is converted to a string using its `Debug` implementation
+    debug: Option<syn::Path>,
+    /// The function used to convert the field to a string, e.g. `path::to::fn`
+    with: Option<syn::Path>,
+    /// The function used to parse the field when importing, e.g. `path::to::fn`
+    parse_with: Option<syn::Path>,
+    /// The string used for `None` when the field is an `Option`, instead of the row's null marker
+    null: Option<syn::LitStr>,
+}
+
+impl ColumnAttrs {
+    /// Removes the `column` attributes from the attributes of a field of type `ty` and parses them
+    fn take_from(attrs: &mut Vec<syn::Attribute>, ty: &syn::Type) -> syn::Result<ColumnAttrs> {
+        let column_attrs = ColumnAttrs::parse(attrs)?;
+        if let Some(flatten) = &column_attrs.flatten {
+            if column_attrs.align.is_some() || column_attrs.format.is_some() || column_attrs.debug.is_some() || column_attrs.with.is_some()
+                || column_attrs.parse_with.is_some() || column_attrs.null.is_some() || column_attrs.skip || column_attrs.default {
+                return Err(syn::Error::new_spanned(flatten, "`flatten` can only be combined with `rename`, which sets the prefix of the columns"));
+            }
+        }
+        let formatters = [column_attrs.format.to_token_stream(), column_attrs.debug.to_token_stream(), column_attrs.with.to_token_stream()];
+        if formatters.iter().filter(|formatter| !formatter.is_empty()).count() > 1 {
+            let formatters = formatters.iter();
+            return Err(syn::Error::new_spanned(quote!(#(#formatters)*), "Only one of `format`, `debug` and `with` can be used for a column"));
+        }
+        if let Some(null) = &column_attrs.null {
+            if option_inner(ty).is_none() {
+                return Err(syn::Error::new(null.span(), "`null` can only be used for fields of type `Option<T>`"));
+            }
+            if column_attrs.with.is_some() {
+                return Err(syn::Error::new(null.span(), "`null` can't be combined with `with`, the function also converts `None` to a string"));
+            }
+            if column_attrs.parse_with.is_some() {
+                return Err(syn::Error::new(null.span(), "`null` can't be combined with `parse_with`, the function also parses `None`"));
+            }
+        }
+        Ok(column_attrs)
+    }
+    
+    /// Removes the `column` attributes and parses them, without checking if they can be combined
+    fn parse(attrs: &mut Vec<syn::Attribute>) -> syn::Result<ColumnAttrs> {
+        let mut column_attrs = ColumnAttrs::default();
+        let (columns, others): (Vec<syn::Attribute>, Vec<syn::Attribute>) = attrs.drain(..)
+            .partition(|attr| attr.path.is_ident("column"));
+        *attrs = others;
+        for attr in columns {
+            if !matches!(attr.tokens.clone().into_iter().next(), Some(proc_macro2::TokenTree::Group(group)) if group.delimiter() == proc_macro2::Delimiter::Parenthesis) {
+                let path = &attr.path;
+                let tokens = &attr.tokens;
+                return Err(syn::Error::new_spanned(quote!(#path #tokens), "Expected `#[column(...)]`"));
+            }
+            attr.parse_args_with(|input: syn::parse::ParseStream| column_attrs.parse_args(input))?;
+        }
+        Ok(column_attrs)
+    }
+    
+    /// Parses the arguments of a single `column` attribute, e.g. `align = "right", default`
+    fn parse_args(&mut self, input: syn::parse::ParseStream) -> syn::Result<()> {
+        while !input.is_empty() {
+            let key: Ident2 = input.parse()?;
+            match key.to_string().as_str() {
+                "align" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let align = parse_lit_str(input, "Expected a string for `align`, e.g. `align = \"right\"`")?;
+                    let variant = match align.value().as_str() {
+                        "left" => "Left",
+                        "right" => "Right",
+                        "center" => "Center",
+                        val => return Err(syn::Error::new_spanned(&align, format!("Unknown alignment: {}. Expected one of `left`, `right` or `center`", val)))
+                    };
+                    self.align = Some(Ident2::new(variant, align.span()));
+                },
+                "rename" => {
+                    input.parse::<syn::Token![=]>()?;
+                    self.rename = Some(parse_lit_str(input, "Expected a string for `rename`, e.g. `rename = \"Display Name\"`")?);
+                },
+                "format" => {
+                    input.parse::<syn::Token![=]>()?;
+                    self.format = Some(parse_lit_str(input, "Expected a format string for `format`, e.g. `format = \"{:.2}\"`")?);
+                },
+                "null" => {
+                    input.parse::<syn::Token![=]>()?;
+                    self.null = Some(parse_lit_str(input, "Expected a string for `null`, e.g. `null = \"-\"`")?);
+                },
+                "with" => {
+                    input.parse::<syn::Token![=]>()?;
+                    self.with = Some(input.parse()?);
+                },
+                "parse_with" => {
+                    input.parse::<syn::Token![=]>()?;
+                    self.parse_with = Some(input.parse()?);
+                },
+                "skip" => self.skip = true,
+                "default" => self.default = true,
+                "flatten" => self.flatten = Some(key.clone().into()),
+                "debug" => self.debug = Some(key.clone().into()),
+                _ => return Err(syn::Error::new(key.span(), format!(
+                    "Unexpected column attribute: {}. Expected one of `align`, `rename`, `skip`, `format`, `debug`, `with`, `parse_with`, `null`, `default` or `flatten`", key
+                )))
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The `#[serde(...)]` attributes of a field that change its key, so a map of columns uses the same
+/// keys as the rows
+#[derive(Default)]
+struct SerdeFieldAttrs {
+    /// `skip`, the field isn't (de)serialized
+    skip: bool,
+    /// `rename = "..."` or `rename(serialize = "...")`
+    serialize_name: Option<String>,
+    /// `rename = "..."` or `rename(deserialize = "...")`
+    deserialize_name: Option<String>,
+}
+
+impl SerdeFieldAttrs {
+    /// Reads the `serde` attributes of a field. Other and invalid attributes are left to serde.
+    fn parse(attrs: &[syn::Attribute]) -> SerdeFieldAttrs {
+        let mut serde_attrs = SerdeFieldAttrs::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident("serde")) {
+            let nested = match attr.parse_meta() {
+                Ok(syn::Meta::List(list)) => list.nested,
+                _ => continue
+            };
+            for meta in nested {
+                match meta {
+                    syn::NestedMeta::Meta(syn::Meta::Path(path)) if path.is_ident("skip") => serde_attrs.skip = true,
+                    syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit: syn::Lit::Str(name), .. })) if path.is_ident("rename") => {
+                        serde_attrs.serialize_name = Some(name.value());
+                        serde_attrs.deserialize_name = Some(name.value());
+                    },
+                    syn::NestedMeta::Meta(syn::Meta::List(list)) if list.path.is_ident("rename") => for meta in list.nested {
+                        if let syn::NestedMeta::Meta(syn::Meta::NameValue(syn::MetaNameValue { path, lit: syn::Lit::Str(name), .. })) = meta {
+                            if path.is_ident("serialize") {
+                                serde_attrs.serialize_name = Some(name.value());
+                            } else if path.is_ident("deserialize") {
+                                serde_attrs.deserialize_name = Some(name.value());
+                            }
+                        }
+                    },
+                    _ => {}
+                }
+            }
+        }
+        serde_attrs
+    }
+}
+
 /// Initialises a struct to be a Table that holds information about a [table row](macro@crate::table_row).
 ///
 /// # Examples
@@ -110,203 +1125,415 @@ pub fn table_row(_attrs: TokenStream, input: TokenStream) -> TokenStream {
 /// #[table(rows = TableRow)]
 /// struct Table {}
 /// ```
+///
+/// # Attributes
+/// - `rows`: the type of the [table rows](macro@crate::table_row), which can be a path to a row
+///   in another module, e.g. `rows = crate::models::UserRow`
+/// - `uid`: the name of the field that uniquely identifies a row, e.g. `uid = "id"`. This
+///   implements [IdTable](simple_tables_core::IdTable) for the table, using the type of the field
+///   as the uid type.
+/// - `indexed`: keeps a hash index of the uid's, so rows can be looked up by their uid in
+///   constant time. Requires `uid` and the uid field has to implement `Hash` and `Eq`.
+/// - `serde`: how the table is serialized, either as a list of rows (`"rows"`, the default) or as
+///   a map of columns (`"columns"`). Requires the `serde` feature.
 #[proc_macro_attribute]
 pub fn table(attrs: TokenStream, input: TokenStream) -> TokenStream {
-    let mut item_struct = parse_macro_input!(input as ItemStruct);
-    
-    // parse the attributes
-    // # Attributes:
-    // - rows: Ident — '=': Punct — TableRowStruct: Ident
-    // - uid: Ident — '=': Punct — "FieldName": Literal (kind: Str)
-    let mut current_attr: Option<&str> = None;
-    let mut table_row_struct: Option<Ident2> = None;
-    let mut uid_field_name: Option<String> = None;
-    attrs.into_iter().for_each(|token| {
-        match token {
-            // https://doc.rust-lang.org/proc_macro/enum.TokenTree.html
-            proc_macro::TokenTree::Group(group) => panic!("Unexpected attribute: {}", group),
-            proc_macro::TokenTree::Ident(ident) => {
-                match &ident.to_string().as_str() {
-                    &"rows" =>  current_attr = Some("rows"),
-                    &"uid" => current_attr = Some("uid"),
-                    val => {
-                        if current_attr == Some("rows") {
-                            table_row_struct = Some(Ident2::new(val, proc_macro2::Span::call_site()));
-                        }  else {
-                            panic!("Unexpected token: {}", val);
-                        }
+    let args = parse_macro_input!(attrs as TableArgs);
+    let item_struct = parse_macro_input!(input as ItemStruct);
+    expand_table(args, item_struct)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The arguments of the `table_row` macro, e.g. `#[table_row(serde, columns = ["x", "y"], null = "-")]`
+#[derive(Default)]
+struct TableRowArgs {
+    /// `serde`
+    serde: Option<Ident2>,
+    /// `columns = ["x", "y"]`, the names of the fields of a tuple struct
+    columns: Option<(Ident2, Vec<syn::LitStr>)>,
+    /// `null = "-"`, the string used for `None` in `Option` fields
+    null: Option<syn::LitStr>,
+}
+
+impl TableRowArgs {
+    /// The string used for `None`, which is empty by default
+    fn null(&self) -> String {
+        self.null.as_ref().map(syn::LitStr::value).unwrap_or_default()
+    }
+}
+
+impl syn::parse::Parse for TableRowArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = TableRowArgs::default();
+        while !input.is_empty() {
+            let key: Ident2 = input.parse()?;
+            let duplicate = match key.to_string().as_str() {
+                "serde" => {
+                    if cfg!(not(feature = "serde")) {
+                        return Err(syn::Error::new(key.span(), "`#[table_row(serde)]` requires the `serde` feature of simple_tables"));
                     }
-                }
-            },
-            proc_macro::TokenTree::Punct(punct) => {
-                if punct.as_char() == '=' && current_attr.is_some() {
-                    // ignored (TODO: enforce syntax)
-                } else if punct.as_char() == ',' {
-                    if current_attr == None {
-                        panic!("Unexpected character: {}", punct.as_char());
-                    } else {
-                        current_attr = None;
+                    args.serde.replace(key.clone()).is_some()
+                },
+                "columns" => {
+                    input.parse::<syn::Token![=]>()?;
+                    if !input.peek(syn::token::Bracket) {
+                        return Err(input.error("Expected a list of column names, e.g. `columns = [\"x\", \"y\"]`"));
                     }
-                } else {
-                    panic!("Unkown character: {}", punct);
-                }
-            },
-            proc_macro::TokenTree::Literal(literal) => {
-                if current_attr == Some("uid") {
-                    // Remove "\"" at begin and end using trim_matches
-                    uid_field_name = Some(literal.to_string().trim_matches(|c| c == '\"').to_string());
-                }
+                    let content;
+                    syn::bracketed!(content in input);
+                    let columns = syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated(&content)?;
+                    args.columns.replace((key.clone(), columns.into_iter().collect())).is_some()
+                },
+                "null" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.null.replace(parse_lit_str(input, "Expected a string for `null`, e.g. `null = \"-\"`")?).is_some()
+                },
+                _ => return Err(syn::Error::new(key.span(), format!("Unknown argument `{}`. Expected one of `serde`, `columns` or `null`", key)))
+            };
+            if duplicate {
+                return Err(syn::Error::new(key.span(), format!("Duplicate argument `{}`", key)));
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
             }
         }
-    });
+        Ok(args)
+    }
+}
+
+/// The arguments of the `table` macro, e.g. `#[table(rows = MyTableRow, uid = "id")]`
+#[derive(Default)]
+struct TableArgs {
+    /// `rows = MyTableRow`, the type of the rows
+    rows: Option<syn::Type>,
+    /// `uid = "id"`, the name of the uid field
+    uid: Option<syn::LitStr>,
+    /// `serde = "rows" | "columns"`, whether to serialize the table as a map of columns
+    serde: Option<syn::LitStr>,
+    /// `indexed`
+    indexed: Option<Ident2>,
+}
+
+impl TableArgs {
+    /// Whether the table is serialized as a map of columns
+    fn serde_columns(&self) -> bool {
+        matches!(&self.serde, Some(serde) if serde.value() == "columns")
+    }
+}
+
+impl syn::parse::Parse for TableArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = TableArgs::default();
+        while !input.is_empty() {
+            let key: Ident2 = input.parse()?;
+            let duplicate = match key.to_string().as_str() {
+                "rows" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.rows.replace(input.parse()?).is_some()
+                },
+                "uid" => {
+                    input.parse::<syn::Token![=]>()?;
+                    args.uid.replace(parse_lit_str(input, "Expected a string for `uid`, e.g. `uid = \"id\"`")?).is_some()
+                },
+                "serde" => {
+                    input.parse::<syn::Token![=]>()?;
+                    let serde = parse_lit_str(input, "Expected a string for `serde`, e.g. `serde = \"columns\"`")?;
+                    match serde.value().as_str() {
+                        "rows" => {},
+                        "columns" if cfg!(feature = "serde") => {},
+                        "columns" => return Err(syn::Error::new(serde.span(), "`#[table(serde = \"columns\")]` requires the `serde` feature of simple_tables")),
+                        val => return Err(syn::Error::new(serde.span(), format!("Unknown serde representation: {}. Expected `rows` or `columns`", val)))
+                    }
+                    args.serde.replace(serde).is_some()
+                },
+                "indexed" => args.indexed.replace(key.clone()).is_some(),
+                _ => return Err(syn::Error::new(key.span(), format!("Unknown argument `{}`. Expected one of `rows`, `uid`, `serde` or `indexed`", key)))
+            };
+            if duplicate {
+                return Err(syn::Error::new(key.span(), format!("Duplicate argument `{}`", key)));
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+/// Parses a string literal, or returns an error with the given message
+fn parse_lit_str(input: syn::parse::ParseStream, message: &str) -> syn::Result<syn::LitStr> {
+    if input.peek(syn::LitStr) {
+        input.parse()
+    } else {
+        Err(input.error(message))
+    }
+}
+
+fn expand_table(args: TableArgs, mut item_struct: ItemStruct) -> syn::Result<TokenStream2> {
+    if !matches!(item_struct.fields, syn::Fields::Named(_)) {
+        return Err(syn::Error::new_spanned(&item_struct, "Only structs with named fields can be used as tables, e.g. `struct MyTable {}`"));
+    }
     
-    if let Some(table_row_struct) = table_row_struct {
-        let field_to_add = quote!(rows: Vec<#table_row_struct>);
+    let serde_columns = args.serde_columns();
+    if let Some(table_row_struct) = args.rows {
+        let krate = crate_path();
         let struct_name = &item_struct.ident;
-        // add field to struct
-        if let syn::Fields::Named(ref mut fields) = item_struct.fields {
-            fields.named.push(
-                syn::Field::parse_named
-                    .parse2(field_to_add)
-                    .unwrap(),
-            );
-        }
+        let generics = item_struct.generics.clone();
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let predicates = where_predicates(where_clause);
+        let mut fields_to_add = vec![
+            quote!(rows: ::std::vec::Vec<#table_row_struct>),
+            // Whether the rows are sorted on their uid, see `IdTable::sort`. The fields added to the
+            // table have reserved names, so they can't clash with the fields of the user.
+            quote!(__simple_tables_sorted: #krate::core::sort::UidSorted),
+        ];
         
         let uid_code: TokenStream2;
-        if let Some(uid) = uid_field_name {
-            uid_code = quote!(const UID: &'static str = #uid;);
-        } else {
-            uid_code = quote!();
-        }
-        
-        let to_string = quote!(
-            // The names of the fields
-                    let field_names = dev_simple_tables_core_table_row_type::get_fields();
-                    // All cells
-                    let mut row_values: Vec<Vec<String>> = Vec::new();
-                    for row in &self.rows {
-                        row_values.push(row.get_field_str());
+        let impl_id_table: TokenStream2;
+        // Keeps the index up to date, see `simple_tables::core::index::UidIndex`
+        let mut index_init = quote!();
+        let mut index_push = quote!();
+        let mut index_insert = quote!();
+        let mut index_remove = quote!();
+        let mut index_invalidate = quote!();
+        // Bounds that only let the implementations apply if the uid is a field of the rows
+        let mut uid_bound = quote!();
+        if let Some(uid) = args.uid {
+            let uid_name = uid.value();
+            let uid_name_type = field_name_type(&uid_name);
+            let row_field = quote!(<#table_row_struct as #krate::core::RowField<#uid_name_type>>);
+            // A uid that isn't a field of the rows is only reported once, on the `uid` value. The
+            // implementations are generic over the uid type or use higher-ranked bounds, so they
+            // don't apply instead of causing more errors.
+            let mut id_table_generics = generics.clone();
+            id_table_generics.params.push(syn::parse_quote!(__Uid));
+            let (id_table_impl_generics, _, _) = id_table_generics.split_for_impl();
+            let id_table = quote!(#krate::core::IdTable<__Uid, #table_row_struct>);
+            let mut id_table_bound = quote!();
+            
+            let index_lookups = if args.indexed.is_some() {
+                // The type of this field reports a uid that isn't a field
+                let index_type = located_at(quote!(#krate::core::index::UidIndex<#row_field::Type>), uid.span());
+                fields_to_add.push(quote!(__simple_tables_index: #index_type));
+                uid_code = quote!(const UID: &'static str = #uid_name;);
+                index_init = quote!(__simple_tables_index: #krate::core::index::UidIndex::new(),);
+                // The same as `IdTable::get_id_from_row`
+                let get_id = quote!(|row: &#table_row_struct| ::std::clone::Clone::clone(#row_field::get_field(row)));
+                index_push = quote!(self.__simple_tables_index.push((#get_id)(&row), self.rows.len()););
+                index_insert = quote!(self.__simple_tables_index.insert(i, &row, &self.rows, #get_id););
+                index_remove = quote!(self.__simple_tables_index.remove(i, &self.rows, #get_id););
+                index_invalidate = quote!(self.__simple_tables_index.invalidate(););
+                uid_bound = quote!(
+                    for<'__a> #table_row_struct: #krate::core::RowField<#uid_name_type>,
+                    for<'__a> #row_field::Type: ::std::hash::Hash + ::std::cmp::Eq + ::std::clone::Clone,
+                );
+                id_table_bound = quote!(+ ::std::hash::Hash + ::std::cmp::Eq);
+                quote!(
+                    fn get_row(&self, uid: __Uid) -> ::std::option::Option<&#table_row_struct> {
+                        self.__simple_tables_index.get(&uid, &self.rows, Self::get_id_from_row).map(|i| &self.rows[i])
                     }
-                    // The sizes of the columns
-                    let mut column_sizes: Vec<usize> = vec![0; field_names.len()];
-                    row_values.iter().for_each(|(row_val)| {
-                        row_val.iter().enumerate().for_each(|(col, col_val)| {
-                            let len = col_val.to_string().chars().count();
-                            if column_sizes[col] < len {
-                                column_sizes[col] = len;
-                            }
-                        });
-                    });
                     
-                    let mut top_line: String = String::from("+-");
-                    let mut headers: String = String::from("| ");
-                    let mut bottom_line: String = String::from("+=");
-                    let mut actual_column_sizes: Vec<usize> = column_sizes.clone();
-                    let total_columns = column_sizes.len();
-                    column_sizes.into_iter().enumerate().for_each(|(col, col_size)| {
-                        let mut local_col_size = col_size.clone();
-                        let field_name = field_names[col];
-                        let field_len = field_name.chars().count();
-                        // Hanlde case when cells are smaller than the title
-                        let left_over = if field_len > local_col_size {
-                            local_col_size = field_len;
-                            actual_column_sizes[col] = field_len;
-                            0
-                        } else {
-                            local_col_size - field_len
-                        };
-                        top_line.push_str(format!("{}-+", "-".repeat(local_col_size)).as_str());
-                        headers.push_str(format!("{}{} |", field_name, " ".repeat(left_over)).as_str());
-                        bottom_line.push_str(format!("{}=+", "=".repeat(local_col_size)).as_str());
-                        if col != total_columns - 1 {
-                            top_line.push_str("-");
-                            headers.push_str(" ");
-                            bottom_line.push_str("=");
-                        }
-                    });
+                    fn get_row_mut(&mut self, uid: __Uid) -> ::std::option::Option<&mut #table_row_struct> {
+                        let i = self.__simple_tables_index.get(&uid, &self.rows, Self::get_id_from_row)?;
+                        self.__simple_tables_sorted.0 = false;
+                        // The uid of the row might be changed, which is checked on the next lookup
+                        self.__simple_tables_index.edit(i, &self.rows, Self::get_id_from_row);
+                        ::std::option::Option::Some(&mut self.rows[i])
+                    }
                     
-                    // Adding the cells to the formatted table
-                    let mut cells: String = String::from("| ");
-                    row_values.into_iter().enumerate().for_each(|(row, row_val)| {
-                        if row != 0 {
-                            cells.push_str("\n| ");
-                        }
-                        row_val.into_iter().enumerate().for_each(|(col, cell_val)| {
-                            let left_over = actual_column_sizes[col] - cell_val.chars().count();
-                            cells.push_str(format!("{}{} |", cell_val, " ".repeat(left_over)).as_str());
-                            if col != total_columns - 1 {
-                            cells.push_str(" ");
-                        }
-                        });
-                        // Add horizontal line to bottom
-                        cells.push_str(format!("\n{}", top_line).as_str());
-                    });
-        );
-        let impl_to_string = quote!(
-            impl ToString for #struct_name {
-                fn to_string(&self) -> String {
-                    #to_string
-                    format!("{}\n{}\n{}\n{}", top_line, headers, bottom_line, cells)
+                    fn get_row_index(&self, uid: __Uid) -> ::std::option::Option<usize> {
+                        self.__simple_tables_index.get(&uid, &self.rows, Self::get_id_from_row)
+                    }
+                )
+            } else {
+                let uid_check = located_at(quote!(#krate::core::check_uid_field::<#table_row_struct, #uid_name_type>), uid.span());
+                uid_code = quote!(
+                    const UID: &'static str = {
+                        #uid_check();
+                        #uid_name
+                    };
+                );
+                quote!()
+            };
+            
+            impl_id_table = quote!(
+                impl #id_table_impl_generics #id_table for #struct_name #ty_generics
+                    where #predicates __Uid: ::std::cmp::PartialEq + ::std::clone::Clone #id_table_bound, for<'__a> #table_row_struct: #krate::core::RowField<#uid_name_type, Type = __Uid>
+                {
+                    fn get_id_from_row(row: &#table_row_struct) -> __Uid {
+                        ::std::clone::Clone::clone(#row_field::get_field(row))
+                    }
+                    
+                    #index_lookups
                 }
+            );
+        } else {
+            if let Some(indexed) = args.indexed {
+                return Err(syn::Error::new(indexed.span(), format!("`indexed` requires a uid, e.g. `#[table(rows = {}, uid = \"id\", indexed)]`", table_row_struct.to_token_stream())));
             }
-        );
+            uid_code = quote!();
+            impl_id_table = quote!();
+        }
+        
+        // add fields to struct
+        if let syn::Fields::Named(ref mut fields) = item_struct.fields {
+            for field_to_add in fields_to_add {
+                fields.named.push(
+                    syn::Field::parse_named
+                        .parse2(field_to_add)
+                        .unwrap(),
+                );
+            }
+        }
+        
+        // The fields besides `rows` of a table that isn't known to be sorted
+        let unsorted_fields_init = quote!(__simple_tables_sorted: #krate::core::sort::UidSorted(false), #index_init);
+        let serde_code = serde_table_code(struct_name, &generics, &table_row_struct, &unsorted_fields_init, &uid_bound, serde_columns);
         
         let output = quote! (
             #[automatically_derived]
             #item_struct
             
-            impl #struct_name {
+            impl #impl_generics #struct_name #ty_generics #where_clause {
                 #uid_code
             }
             
-            type dev_simple_tables_core_table_row_type = #table_row_struct; // TODO: append the name of the struct to this type to avoid collisions
-            
-            impl simple_tables::core::Table<#table_row_struct> for #struct_name {
-                fn new() -> #struct_name {
-                    #struct_name { rows: Vec::new() }
+            impl #impl_generics #krate::core::Table<#table_row_struct> for #struct_name #ty_generics where #predicates #uid_bound {
+                fn new() -> Self {
+                    Self { rows: ::std::vec::Vec::new(), __simple_tables_sorted: #krate::core::sort::UidSorted(true), #index_init }
                 }
                 
-                fn from_vec(vec: &Vec<#table_row_struct>) -> #struct_name {
-                    #struct_name { rows: vec.to_vec() }
+                fn from_rows(rows: ::std::vec::Vec<#table_row_struct>) -> Self {
+                    Self { rows, #unsorted_fields_init }
                 }
                 
-                fn get_rows(&self) -> &Vec<#table_row_struct> {
+                fn get_rows(&self) -> &::std::vec::Vec<#table_row_struct> {
                     &self.rows
                 }
                 
-                fn get_rows_mut(&mut self) -> &mut Vec<#table_row_struct> {
+                fn get_rows_mut(&mut self) -> &mut ::std::vec::Vec<#table_row_struct> {
+                    self.__simple_tables_sorted.0 = false;
+                    #index_invalidate
                     &mut self.rows
                 }
                 
+                fn is_sorted(&self) -> bool {
+                    self.__simple_tables_sorted.0
+                }
+                
+                fn set_sorted(&mut self, sorted: bool) {
+                    self.__simple_tables_sorted.0 = sorted;
+                }
+                
                 fn push(&mut self, row: #table_row_struct) {
+                    self.__simple_tables_sorted.0 = false;
+                    #index_push
                     self.rows.push(row);
                 }
                 
                 fn insert_top(&mut self, row: #table_row_struct) {
-                    self.rows.insert(0, row);
+                    <Self as #krate::core::Table<#table_row_struct>>::insert(self, 0, row);
                 }
                 
                 fn insert(&mut self, i: usize, row: #table_row_struct) {
+                    self.__simple_tables_sorted.0 = false;
+                    #index_insert
                     self.rows.insert(i, row);
                 }
+                
+                fn rm_row_at(&mut self, i: usize) -> #table_row_struct {
+                    // Removing a row doesn't change the order of the other rows
+                    #index_remove
+                    self.rows.remove(i)
+                }
             }
             
-            #impl_to_string
+            impl #impl_generics ::std::fmt::Display for #struct_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    ::std::write!(f, "{}", #krate::core::render::Renderer::default().render(&self.rows))
+                }
+            }
+            
+            impl #impl_generics ::std::fmt::Debug for #struct_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    ::std::write!(f, "{}", #krate::core::render::Renderer::default().render(&self.rows))
+                }
+            }
             
-            impl std::fmt::Debug for #struct_name {
-                fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                    #to_string
-                    let output = format!("{}\n{}\n{}\n{}", top_line, headers, bottom_line, cells);
-                    write!(f, "{}", output)
+            impl #impl_generics ::std::iter::FromIterator<#table_row_struct> for #struct_name #ty_generics where #predicates #uid_bound {
+                fn from_iter<I: ::std::iter::IntoIterator<Item = #table_row_struct>>(iter: I) -> Self {
+                    Self { rows: ::std::iter::Iterator::collect(::std::iter::IntoIterator::into_iter(iter)), #unsorted_fields_init }
                 }
             }
+            
+            #impl_id_table
+            
+            #serde_code
         );
     
-        TokenStream::from(output)
+        Ok(output)
+    } else {
+        Err(syn::Error::new(proc_macro2::Span::call_site(), "Please specify a struct to use as the data type for the table rows. \
+        e.g. `#[table(rows = TableRowStruct)]`. Refer to the `table` macro documentation for more info."))
+    }
+}
+
+/// Implements `Serialize` and `Deserialize` for a table, either as a sequence of rows or as a map
+/// of columns. The bounds are higher-ranked so that tables of rows that can't be serialized still
+/// compile.
+#[cfg(feature = "serde")]
+fn serde_table_code(struct_name: &Ident2, generics: &syn::Generics, table_row_struct: &syn::Type, fields_init: &TokenStream2, uid_bound: &TokenStream2, columns: bool) -> TokenStream2 {
+    let krate = crate_path();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let predicates = where_predicates(where_clause);
+    let predicates = quote!(#predicates #uid_bound);
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote!('de));
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+    if columns {
+        quote!(
+            impl #impl_generics #krate::core::serde::Serialize for #struct_name #ty_generics
+                where #predicates for<'__a> #table_row_struct: #krate::core::serde_columns::SerdeColumns
+            {
+                fn serialize<S: #krate::core::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                    <#table_row_struct as #krate::core::serde_columns::SerdeColumns>::serialize_columns(&self.rows, serializer)
+                }
+            }
+            
+            impl #de_impl_generics #krate::core::serde::Deserialize<'de> for #struct_name #ty_generics
+                where #predicates for<'__a> #table_row_struct: #krate::core::serde_columns::SerdeColumns
+            {
+                fn deserialize<D: #krate::core::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+                    ::std::result::Result::Ok(Self { rows: <#table_row_struct as #krate::core::serde_columns::SerdeColumns>::deserialize_columns(deserializer)?, #fields_init })
+                }
+            }
+        )
     } else {
-        panic!("Please specify a struct to use as the data type for the table rows. \
-        e.g. `#[table(rows = TableRowStruct)]`. Refer to the `table` macro documentation for more info.")
+        quote!(
+            impl #impl_generics #krate::core::serde::Serialize for #struct_name #ty_generics
+                where #predicates for<'__a> #table_row_struct: #krate::core::serde::Serialize
+            {
+                fn serialize<S: #krate::core::serde::Serializer>(&self, serializer: S) -> ::std::result::Result<S::Ok, S::Error> {
+                    #krate::core::serde::Serialize::serialize(&self.rows, serializer)
+                }
+            }
+            
+            impl #de_impl_generics #krate::core::serde::Deserialize<'de> for #struct_name #ty_generics
+                where #predicates #table_row_struct: #krate::core::serde::Deserialize<'de>
+            {
+                fn deserialize<D: #krate::core::serde::Deserializer<'de>>(deserializer: D) -> ::std::result::Result<Self, D::Error> {
+                    ::std::result::Result::Ok(Self { rows: #krate::core::serde::Deserialize::deserialize(deserializer)?, #fields_init })
+                }
+            }
+        )
     }
 }
 
+#[cfg(not(feature = "serde"))]
+fn serde_table_code(_: &Ident2, _: &syn::Generics, _: &syn::Type, _: &TokenStream2, _: &TokenStream2, _: bool) -> TokenStream2 {
+    // Using `serde = "columns"` without the feature is already reported as an error
+    quote!()
+}
+
 // https://mbuffett.com/posts/incomplete-macro-walkthrough/
\ No newline at end of file
@@ -48,7 +48,7 @@
 //!   MyTableRow{ id: 3, name: "The Beatles".to_string(), email: "info@beatles.com".to_string(), address: "England".to_string()}
 //! ];
 //!
-//! let table = MyTable::from_vec(&rows);
+//! let table = MyTable::from_rows(rows);
 //! let s = table.to_string();
 //! println!("{}", s);
 //! ```
@@ -58,13 +58,13 @@
 //! +----+---------------+-------------------+---------+
 //! | id | name          | email             | address |
 //! +====+===============+===================+=========+
-//! | 0  | David Bowie   | david@bowie.com   | England |
+//! |  0 | David Bowie   | david@bowie.com   | England |
 //! +----+---------------+-------------------+---------+
-//! | 1  | David Gilmour | david@gilmour.com | England |
+//! |  1 | David Gilmour | david@gilmour.com | England |
 //! +----+---------------+-------------------+---------+
-//! | 2  | Opeth         | info@opeth.com    | Sweden  |
+//! |  2 | Opeth         | info@opeth.com    | Sweden  |
 //! +----+---------------+-------------------+---------+
-//! | 3  | The Beatles   | info@beatles.com  | England |
+//! |  3 | The Beatles   | info@beatles.com  | England |
 //! +----+---------------+-------------------+---------+
 //! ```
 //!
@@ -93,5 +93,17 @@ pub mod macros {
 pub use core::Table;
 pub use core::TableRow;
 pub use core::IdTable;
+pub use core::ParseTableRow;
 
-pub use core::error;
\ No newline at end of file
+pub use core::csv;
+pub use core::error;
+pub use core::html;
+pub use core::index;
+pub use core::markdown;
+pub use core::render;
+pub use core::sort;
+pub use core::style;
+#[cfg(feature = "serde")]
+pub use core::serde;
+#[cfg(feature = "serde")]
+pub use core::serde_columns;
\ No newline at end of file
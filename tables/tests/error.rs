@@ -43,4 +43,10 @@ mod kind {
         let err = TableErrorKind::CouldNotRemove;
         assert_eq!("CouldNotRemove", err.to_string().as_str());
     }
+    
+    #[test]
+    fn dbg_duplicate_uid() {
+        let err = TableErrorKind::DuplicateUid;
+        assert_eq!("DuplicateUid", format!("{:?}", err).as_str());
+    }
 }
@@ -0,0 +1,327 @@
+mod markdown {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[test]
+    fn to_markdown() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32,
+            name: String
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let vec: Vec<TableRow> = vec![TableRow{id: 1000, name: String::from("Abc")}, TableRow{id: 2, name: String::from("Bd")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        let markdown = "\
+|   id | name |
+| ---: | :--- |
+| 1000 | Abc  |
+|    2 | Bd   |";
+        assert_eq!(markdown, table.to_markdown());
+    }
+    
+    #[test]
+    fn escape() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            name: String
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let vec: Vec<TableRow> = vec![TableRow{name: String::from("a|b")}, TableRow{name: String::from("c\nd")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        let markdown = "\
+| name   |
+| :----- |
+| a\\|b   |
+| c<br>d |";
+        assert_eq!(markdown, table.to_markdown());
+    }
+    
+    #[test]
+    fn alignment() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            #[column(align = "center")]
+            a: String,
+            #[column(align = "left")]
+            b: i32
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let table: MyTable = MyTable::from_vec(&vec![TableRow{a: String::from("x"), b: 1}]);
+        
+        let markdown = "\
+|  a  | b   |
+| :-: | :-- |
+|  x  | 1   |";
+        assert_eq!(markdown, table.to_markdown());
+    }
+    
+    #[test]
+    fn empty() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        assert_eq!("|  id |\n| --: |", MyTable::new().to_markdown());
+    }
+}
+
+mod csv {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        name: String
+    }
+    
+    #[table(rows = TableRow)]
+    struct MyTable {}
+    
+    #[test]
+    fn to_csv() {
+        let vec: Vec<TableRow> = vec![TableRow{id: 1000, name: String::from("Abc")}, TableRow{id: 2, name: String::from("Bd")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        assert_eq!("id,name\r\n1000,Abc\r\n2,Bd\r\n", table.to_csv());
+    }
+    
+    #[test]
+    fn quoting() {
+        let vec: Vec<TableRow> = vec![
+            TableRow{id: 1, name: String::from("Crosby, Stills & Nash")},
+            TableRow{id: 2, name: String::from("\"Weird Al\" Yankovic")},
+            TableRow{id: 3, name: String::from("Line\nbreak")},
+            TableRow{id: 4, name: String::from("Tab\there")}
+        ];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        assert_eq!(
+            "id,name\r\n1,\"Crosby, Stills & Nash\"\r\n2,\"\"\"Weird Al\"\" Yankovic\"\r\n3,\"Line\nbreak\"\r\n4,Tab\there\r\n",
+            table.to_csv()
+        );
+    }
+    
+    #[test]
+    fn to_tsv() {
+        let vec: Vec<TableRow> = vec![TableRow{id: 1, name: String::from("Crosby, Stills & Nash")}, TableRow{id: 2, name: String::from("Tab\there")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        assert_eq!("id\tname\r\n1\tCrosby, Stills & Nash\r\n2\t\"Tab\there\"\r\n", table.to_tsv());
+    }
+    
+    #[test]
+    fn write_delimited() {
+        let vec: Vec<TableRow> = vec![TableRow{id: 1, name: String::from("A;B")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        let mut out: Vec<u8> = Vec::new();
+        table.write_delimited(&mut out, ';').unwrap();
+        assert_eq!("id;name\r\n1;\"A;B\"\r\n", String::from_utf8(out).unwrap());
+    }
+}
+
+mod csv_import {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    use simple_tables::error::TableErrorKind;
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        name: String
+    }
+    
+    impl PartialEq<Self> for TableRow {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id && self.name == other.name
+        }
+    }
+    
+    #[table(rows = TableRow)]
+    struct MyTable {}
+    
+    #[test]
+    fn from_csv_reader() {
+        let csv = "id,name\r\n1,Opeth\r\n2,\"Crosby, Stills & Nash\"\r\n3,\"\"\"Weird Al\"\" Yankovic\"\r\n";
+        let table = MyTable::from_csv_reader(csv.as_bytes()).unwrap();
+        
+        let vec = vec![
+            TableRow { id: 1, name: "Opeth".to_string() },
+            TableRow { id: 2, name: "Crosby, Stills & Nash".to_string() },
+            TableRow { id: 3, name: "\"Weird Al\" Yankovic".to_string() }
+        ];
+        assert_eq!(&vec, table.get_rows());
+    }
+    
+    #[test]
+    fn round_trip() {
+        let vec = vec![
+            TableRow { id: 1, name: "Line\nbreak".to_string() },
+            TableRow { id: 2, name: "".to_string() },
+            TableRow { id: 3, name: "Tab\there".to_string() }
+        ];
+        let table = MyTable::from_vec(&vec);
+        
+        assert_eq!(&vec, MyTable::from_csv_reader(table.to_csv().as_bytes()).unwrap().get_rows());
+        assert_eq!(&vec, MyTable::from_tsv_reader(table.to_tsv().as_bytes()).unwrap().get_rows());
+    }
+    
+    #[test]
+    fn map_columns_by_name() {
+        let csv = "country,name,id\nSweden,Opeth,1\n\nEngland,Pink Floyd,2";
+        let table = MyTable::from_csv_reader(csv.as_bytes()).unwrap();
+        
+        let vec = vec![
+            TableRow { id: 1, name: "Opeth".to_string() },
+            TableRow { id: 2, name: "Pink Floyd".to_string() }
+        ];
+        assert_eq!(&vec, table.get_rows());
+    }
+    
+    #[test]
+    fn parse_error() {
+        let csv = "id,name\n1,\"Multi\nline\"\ntwo,Opeth\n";
+        let error = MyTable::from_csv_reader(csv.as_bytes()).unwrap_err();
+        
+        match error.kind {
+            TableErrorKind::CouldNotParse { line, row, ref column } => {
+                assert_eq!(4, line);
+                assert_eq!(1, row);
+                assert_eq!("id", column);
+            },
+            _ => panic!("Unexpected error: {:?}", error)
+        }
+        assert_eq!("Could not parse column `id` of row 1 (line 4): invalid digit found in string", error.to_string());
+    }
+    
+    #[test]
+    fn invalid_csv() {
+        let missing_column = MyTable::from_csv_reader("id\n1".as_bytes()).unwrap_err();
+        assert_eq!("InvalidCsv { line: 1 }: Invalid CSV on line 1: The header has no column `name`", format!("{:?}", missing_column));
+        
+        let wrong_count = MyTable::from_csv_reader("id,name\n1,Opeth,Sweden".as_bytes()).unwrap_err();
+        assert_eq!("Invalid CSV on line 2: Expected 2 fields, but found 3", wrong_count.to_string());
+        
+        let unterminated = MyTable::from_csv_reader("id,name\n1,\"Opeth".as_bytes()).unwrap_err();
+        assert_eq!("Invalid CSV on line 2: Unterminated quoted field", unterminated.to_string());
+        
+        let after_quote = MyTable::from_csv_reader("id,name\n1,\"Opeth\"x".as_bytes()).unwrap_err();
+        assert_eq!("Invalid CSV on line 2: Unexpected character `x` after a closing quote", after_quote.to_string());
+        
+        let empty = MyTable::from_csv_reader("".as_bytes()).unwrap_err();
+        assert_eq!("Invalid CSV on line 1: The input has no header", empty.to_string());
+    }
+    
+    #[test]
+    fn rows_without_from_str() {
+        #[derive(Debug, Clone)]
+        struct NotParsable;
+        
+        impl std::fmt::Display for NotParsable {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "-")
+            }
+        }
+        
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Row {
+            id: u32,
+            value: NotParsable
+        }
+        
+        #[table(rows = Row)]
+        struct NotParsableTable {}
+        
+        let table = NotParsableTable::from_vec(&vec![Row { id: 1, value: NotParsable }]);
+        assert_eq!("id,value\r\n1,-\r\n", table.to_csv());
+    }
+}
+
+mod html {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    use simple_tables::html::HtmlOptions;
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        name: String
+    }
+    
+    #[table(rows = TableRow)]
+    struct MyTable {}
+    
+    #[test]
+    fn to_html() {
+        let table = MyTable::from_vec(&vec![TableRow { id: 1, name: "Opeth".to_string() }]);
+        
+        let html = r#"<table>
+  <thead>
+    <tr>
+      <th class="column-id">id</th>
+      <th class="column-name">name</th>
+    </tr>
+  </thead>
+  <tbody>
+    <tr>
+      <td class="column-id">1</td>
+      <td class="column-name">Opeth</td>
+    </tr>
+  </tbody>
+</table>"#;
+        assert_eq!(html, table.to_html());
+    }
+    
+    #[test]
+    fn escape() {
+        let table = MyTable::from_vec(&vec![TableRow { id: 1, name: "<b>\"Tom\" & 'Jerry'</b>\nCartoon".to_string() }]);
+        
+        assert!(table.to_html().contains(
+            r#"<td class="column-name">&lt;b&gt;&quot;Tom&quot; &amp; &#39;Jerry&#39;&lt;/b&gt;<br>Cartoon</td>"#
+        ));
+    }
+    
+    #[test]
+    fn options() {
+        let table = MyTable::new();
+        let mut options = HtmlOptions::new().id("bands").class("table striped");
+        options.column_class_prefix = String::from("bands-");
+        
+        let html = r#"<table class="table striped" id="bands">
+  <thead>
+    <tr>
+      <th class="bands-id">id</th>
+      <th class="bands-name">name</th>
+    </tr>
+  </thead>
+  <tbody>
+  </tbody>
+</table>"#;
+        assert_eq!(html, table.to_html_with(&options));
+    }
+}
@@ -22,5 +22,9 @@
 
 mod table;
 mod table_row;
+#[allow(clippy::module_inception)]
 mod error;
+mod render;
+mod export;
+mod serde;
 // mod table_row;
\ No newline at end of file
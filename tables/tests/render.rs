@@ -0,0 +1,248 @@
+mod renderer {
+    use simple_tables::macros::table_row;
+    use simple_tables::render::Renderer;
+    use simple_tables::style::TableStyle;
+    
+    #[test]
+    fn render_rows() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32,
+            name: String
+        }
+        
+        let rows = vec![TableRow { id: 1, name: "Opeth".to_string() }];
+        let psql = "\
+id | name
+---+------
+ 1 | Opeth";
+        assert_eq!(psql, Renderer::new(TableStyle::PSQL).render(&rows));
+    }
+    
+    #[test]
+    fn render_cells() {
+        let cells = vec![vec!["1", "Opeth"], vec!["2", "Slipknot"]];
+        let ascii_table = "\
++----+----------+
+| id | name     |
++====+==========+
+| 1  | Opeth    |
++----+----------+
+| 2  | Slipknot |
++----+----------+";
+        assert_eq!(ascii_table, Renderer::default().render_cells(&["id", "name"], &cells));
+    }
+    
+    #[test]
+    fn render_empty() {
+        let cells: Vec<Vec<String>> = Vec::new();
+        let ascii_table = "\
++----+------+
+| id | name |
++====+======+
++----+------+";
+        assert_eq!(ascii_table, Renderer::default().render_cells(&["id", "name"], &cells));
+    }
+}
+
+mod alignment {
+    use simple_tables::TableRow;
+    use simple_tables::macros::table_row;
+    use simple_tables::render::{Alignment, Renderer};
+    
+    #[test]
+    fn numeric_default() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Row {
+            id: u32,
+            name: String,
+            score: f64
+        }
+        
+        assert_eq!(vec![Alignment::Right, Alignment::Left, Alignment::Right], Row::get_field_alignments());
+    }
+    
+    #[test]
+    fn column_attribute() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Row {
+            #[column(align = "left")]
+            id: u32,
+            #[column(align = "center")]
+            name: String
+        }
+        
+        let rows = vec![Row { id: 1000, name: "Abc".to_string() }, Row { id: 2, name: "Bdefg".to_string() }];
+        let ascii_table = "\
++------+-------+
+| id   | name  |
++======+=======+
+| 1000 |  Abc  |
++------+-------+
+| 2    | Bdefg |
++------+-------+";
+        assert_eq!(vec![Alignment::Left, Alignment::Center], Row::get_field_alignments());
+        assert_eq!(ascii_table, Renderer::default().render(&rows));
+    }
+    
+    #[test]
+    fn render_time() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Row {
+            id: u32,
+            name: String
+        }
+        
+        let rows = vec![Row { id: 1000, name: "Abc".to_string() }, Row { id: 2, name: "Bd".to_string() }];
+        let ascii_table = "\
++------+------+
+| id   | name |
++======+======+
+| 1000 |  Abc |
++------+------+
+| 2    |   Bd |
++------+------+";
+        let renderer = Renderer::default()
+            .align("id", Alignment::Left)
+            .align("name", Alignment::Right);
+        assert_eq!(ascii_table, renderer.render(&rows));
+    }
+}
+
+mod wrapping {
+    use simple_tables::render::Renderer;
+    
+    #[test]
+    fn wrap_on_words() {
+        let cells = vec![vec!["1", "Nick Cave & The Bad Seeds"], vec!["2", "Opeth"]];
+        let ascii_table = "\
++----+-----------+
+| id | name      |
++====+===========+
+| 1  | Nick Cave |
+|    | & The Bad |
+|    | Seeds     |
++----+-----------+
+| 2  | Opeth     |
++----+-----------+";
+        assert_eq!(ascii_table, Renderer::default().max_width(10).render_cells(&["id", "name"], &cells));
+    }
+    
+    #[test]
+    fn break_long_words() {
+        let cells = vec![vec!["info@slipknot.com is an email"]];
+        let ascii_table = "\
++--------+
+| email  |
++========+
+| info@s |
+| lipkno |
+| t.com  |
+| is an  |
+| email  |
++--------+";
+        assert_eq!(ascii_table, Renderer::default().max_width(6).render_cells(&["email"], &cells));
+    }
+    
+    #[test]
+    fn wrap_header() {
+        let cells = vec![vec!["1"]];
+        let ascii_table = "\
++-----+
+| the |
+| uid |
++=====+
+| 1   |
++-----+";
+        assert_eq!(ascii_table, Renderer::default().max_width(5).render_cells(&["the uid"], &cells));
+    }
+    
+    #[test]
+    fn max_column_width() {
+        let cells = vec![vec!["Nick Cave", "Nick Cave"]];
+        let ascii_table = "\
++-----------+------+
+| a         | b    |
++===========+======+
+| Nick Cave | Nick |
+|           | Cave |
++-----------+------+";
+        let renderer = Renderer::default().max_width(20).max_column_width("b", 4);
+        assert_eq!(ascii_table, renderer.render_cells(&["a", "b"], &cells));
+    }
+    
+    #[test]
+    fn multi_line_cells() {
+        let cells = vec![vec!["1", "Abbey Road\nLet It Be"], vec!["2", ""]];
+        let ascii_table = "\
++----+------------+
+| id | albums     |
++====+============+
+| 1  | Abbey Road |
+|    | Let It Be  |
++----+------------+
+| 2  |            |
++----+------------+";
+        assert_eq!(ascii_table, Renderer::default().render_cells(&["id", "albums"], &cells));
+    }
+}
+
+mod unicode {
+    use simple_tables::render::{display_width, Renderer};
+    
+    #[test]
+    fn widths() {
+        assert_eq!(5, display_width("Opeth"));
+        assert_eq!(6, display_width("坂本龍一"[..9].to_string().as_str()));
+        assert_eq!(8, display_width("坂本龍一"));
+        assert_eq!(2, display_width("🎸"));
+        assert_eq!(2, display_width("👨‍👩‍👧"));
+        assert_eq!(7, display_width("Bjo\u{308}rk ñ"));
+        assert_eq!(3, display_width("a\u{200B}bc"));
+    }
+    
+    #[test]
+    fn wide_characters() {
+        let cells = vec![vec!["坂本龍一", "🎸"], vec!["Opeth", "-"]];
+        let ascii_table = "\
++----------+-------+
+| name     | instr |
++==========+=======+
+| 坂本龍一 | 🎸    |
++----------+-------+
+| Opeth    | -     |
++----------+-------+";
+        assert_eq!(ascii_table, Renderer::default().render_cells(&["name", "instr"], &cells));
+    }
+    
+    #[test]
+    fn combining_characters() {
+        let cells = vec![vec!["Bjo\u{308}rk"], vec!["Sigur Ro\u{301}s"]];
+        let ascii_table = "\
++-----------+
+| name      |
++===========+
+| Bjo\u{308}rk     |
++-----------+
+| Sigur Ro\u{301}s |
++-----------+";
+        assert_eq!(ascii_table, Renderer::default().render_cells(&["name"], &cells));
+    }
+    
+    #[test]
+    fn wrap_wide_characters() {
+        let cells = vec![vec!["坂本龍一"]];
+        let ascii_table = "\
++------+
+| n    |
++======+
+| 坂本 |
+| 龍一 |
++------+";
+        assert_eq!(ascii_table, Renderer::default().max_width(5).render_cells(&["n"], &cells));
+    }
+}
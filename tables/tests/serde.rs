@@ -0,0 +1,285 @@
+#![cfg(feature = "serde")]
+
+mod rows {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        #[serde(rename = "band")]
+        name: String
+    }
+    
+    impl PartialEq<Self> for TableRow {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id && self.name == other.name
+        }
+    }
+    
+    #[table(rows = TableRow)]
+    struct MyTable {}
+    
+    #[test]
+    fn serialize() {
+        let table = MyTable::from_vec(&vec![TableRow { id: 1, name: "Opeth".to_string() }, TableRow { id: 2, name: "Slipknot".to_string() }]);
+        
+        assert_eq!(r#"[{"id":1,"band":"Opeth"},{"id":2,"band":"Slipknot"}]"#, serde_json::to_string(&table).unwrap());
+    }
+    
+    #[test]
+    fn deserialize() {
+        let table: MyTable = serde_json::from_str(r#"[{"id":1,"band":"Opeth"},{"id":2,"band":"Slipknot"}]"#).unwrap();
+        
+        let vec = vec![TableRow { id: 1, name: "Opeth".to_string() }, TableRow { id: 2, name: "Slipknot".to_string() }];
+        assert_eq!(&vec, table.get_rows());
+    }
+    
+    #[test]
+    fn skipped_fields() {
+        #[table_row(serde)]
+        #[derive(Debug, Clone)]
+        struct Band {
+            name: String,
+            #[column(skip)]
+            rank: u32,
+        }
+        
+        #[table(rows = Band)]
+        struct BandTable {}
+        
+        let table = BandTable::from_vec(&vec![Band { name: "Opeth".to_string(), rank: 1 }]);
+        assert_eq!(r#"[{"name":"Opeth"}]"#, serde_json::to_string(&table).unwrap());
+        
+        let table: BandTable = serde_json::from_str(r#"[{"name":"Opeth","rank":1}]"#).unwrap();
+        assert_eq!(0, table.get_rows()[0].rank);
+    }
+    
+    #[test]
+    fn rows_with_own_derive() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        #[derive(simple_tables::serde::Serialize)]
+        #[serde(crate = "simple_tables::serde")]
+        struct Row {
+            id: u32
+        }
+        
+        #[table(rows = Row)]
+        struct OwnDeriveTable {}
+        
+        let table = OwnDeriveTable::from_vec(&vec![Row { id: 1 }]);
+        assert_eq!(r#"[{"id":1}]"#, serde_json::to_string(&table).unwrap());
+    }
+    
+    #[test]
+    fn rows_without_serde() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Row {
+            id: u32
+        }
+        
+        #[table(rows = Row)]
+        struct NoSerdeTable {}
+        
+        assert_eq!(1, NoSerdeTable::from_vec(&vec![Row { id: 1 }]).row_count());
+    }
+}
+
+mod columns {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        name: String
+    }
+    
+    impl PartialEq<Self> for TableRow {
+        fn eq(&self, other: &Self) -> bool {
+            self.id == other.id && self.name == other.name
+        }
+    }
+    
+    #[table(rows = TableRow, serde = "columns")]
+    struct MyTable {}
+    
+    #[test]
+    fn serialize() {
+        let table = MyTable::from_vec(&vec![TableRow { id: 1, name: "Opeth".to_string() }, TableRow { id: 2, name: "Slipknot".to_string() }]);
+        
+        assert_eq!(r#"{"id":[1,2],"name":["Opeth","Slipknot"]}"#, serde_json::to_string(&table).unwrap());
+    }
+    
+    #[test]
+    fn deserialize() {
+        let table: MyTable = serde_json::from_str(r#"{"name":["Opeth","Slipknot"],"id":[1,2]}"#).unwrap();
+        
+        let vec = vec![TableRow { id: 1, name: "Opeth".to_string() }, TableRow { id: 2, name: "Slipknot".to_string() }];
+        assert_eq!(&vec, table.get_rows());
+    }
+    
+    #[test]
+    fn different_lengths() {
+        let error = serde_json::from_str::<MyTable>(r#"{"id":[1,2],"name":["Opeth"]}"#).unwrap_err();
+        
+        assert_eq!("column `name` has 1 values, expected 2", error.to_string());
+    }
+    
+    #[test]
+    fn column_attributes() {
+        #[table_row(serde)]
+        #[derive(Debug, Clone)]
+        struct Band {
+            #[column(rename = "Band")]
+            name: String,
+            #[column(skip)]
+            rank: u32,
+        }
+        
+        #[table(rows = Band, serde = "columns")]
+        struct BandTable {}
+        
+        let table = BandTable::from_vec(&vec![Band { name: "Opeth".to_string(), rank: 1 }]);
+        assert_eq!(r#"{"Band":["Opeth"]}"#, serde_json::to_string(&table).unwrap());
+        
+        let table: BandTable = serde_json::from_str(r#"{"Band":["Opeth"]}"#).unwrap();
+        assert_eq!("Opeth", table.get_rows()[0].name);
+        assert_eq!(0, table.get_rows()[0].rank);
+    }
+    
+    #[test]
+    fn serde_attributes() {
+        #[table_row(serde)]
+        #[derive(Debug, Clone, PartialEq)]
+        struct Band {
+            id: u32,
+            #[serde(rename = "band")]
+            name: String,
+            #[serde(skip)]
+            rank: u32,
+        }
+        
+        #[table(rows = Band)]
+        struct BandRows {}
+        
+        #[table(rows = Band, serde = "columns")]
+        struct BandColumns {}
+        
+        let rows = vec![Band { id: 1, name: "Opeth".to_string(), rank: 0 }];
+        let json = serde_json::to_string(&BandRows::from_vec(&rows)).unwrap();
+        assert_eq!(r#"[{"id":1,"band":"Opeth"}]"#, json);
+        assert_eq!(&rows, serde_json::from_str::<BandRows>(&json).unwrap().get_rows());
+        
+        let json = serde_json::to_string(&BandColumns::from_vec(&rows)).unwrap();
+        assert_eq!(r#"{"id":[1],"band":["Opeth"]}"#, json);
+        assert_eq!(&rows, serde_json::from_str::<BandColumns>(&json).unwrap().get_rows());
+    }
+}
+
+mod generics {
+    use std::fmt::Display;
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone)]
+    struct Measurement<T: Display> {
+        name: String,
+        value: T,
+    }
+    
+    #[table(rows = Measurement<T>, serde = "columns")]
+    struct Measurements<T: Display + Clone> {}
+    
+    #[test]
+    fn columns() {
+        let table = Measurements::from_vec(&vec![Measurement { name: "temperature".to_string(), value: 21.5 }]);
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(r#"{"name":["temperature"],"value":[21.5]}"#, json);
+        
+        let table: Measurements<f32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(21.5, table.get_rows()[0].value);
+    }
+}
+
+mod tuple_structs {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde, columns = ["x", "y"])]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point(i32, i32);
+    
+    #[table(rows = Point, serde = "columns")]
+    struct Points {}
+    
+    #[test]
+    fn columns() {
+        let table = Points::from_vec(&vec![Point(1, 2), Point(3, 4)]);
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(r#"{"x":[1,3],"y":[2,4]}"#, json);
+        
+        let table: Points = serde_json::from_str(&json).unwrap();
+        assert_eq!(&vec![Point(1, 2), Point(3, 4)], table.get_rows());
+    }
+}
+
+mod enums {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Login { user: String, #[column(skip)] attempts: u8 },
+        Restart,
+    }
+    
+    #[table(rows = Event)]
+    struct Log {}
+    
+    #[test]
+    fn rows() {
+        let table = Log::from_rows(vec![Event::Login { user: "david".to_string(), attempts: 2 }, Event::Restart]);
+        let json = serde_json::to_string(&table).unwrap();
+        assert_eq!(r#"[{"Login":{"user":"david"}},"Restart"]"#, json);
+        
+        let table: Log = serde_json::from_str(&json).unwrap();
+        assert_eq!(&vec![Event::Login { user: "david".to_string(), attempts: 0 }, Event::Restart], table.get_rows());
+    }
+}
+
+mod flatten {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::Table;
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Address {
+        street: String,
+    }
+    
+    #[table_row(serde)]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Person {
+        name: String,
+        #[column(flatten)]
+        address: Address,
+    }
+    
+    #[table(rows = Person, serde = "columns")]
+    struct People {}
+    
+    #[test]
+    fn nested_values() {
+        let rows = vec![Person { name: "David".to_string(), address: Address { street: "Main Street".to_string() } }];
+        let json = serde_json::to_string(&People::from_vec(&rows)).unwrap();
+        assert_eq!(r#"{"name":["David"],"address":[{"street":"Main Street"}]}"#, json);
+        assert_eq!(&rows, serde_json::from_str::<People>(&json).unwrap().get_rows());
+    }
+}
@@ -5,6 +5,7 @@ mod table_trait {
     #[test]
     fn new_is_empty() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -19,6 +20,7 @@ mod table_trait {
     #[test]
     fn from_vec() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -39,9 +41,41 @@ mod table_trait {
         assert_eq!(&vec, table.get_rows())
     }
     
+    #[test]
+    fn rows_without_clone() {
+        #[table_row]
+        struct Task {
+            name: String,
+            #[column(skip)]
+            run: Box<dyn Fn() -> u32>,
+        }
+        
+        #[table(rows = Task, uid = "name")]
+        struct Tasks {}
+        
+        let mut table = Tasks::from_rows(vec![Task { name: "one".to_string(), run: Box::new(|| 1) }]);
+        table.push(Task { name: "two".to_string(), run: Box::new(|| 2) });
+        assert_eq!(2, (simple_tables::IdTable::get_row(&table, "two".to_string()).unwrap().run)());
+        assert_eq!(vec!["one", "two"], table.get_column(|row| row.name.clone()));
+        
+        let table: Tasks = (1..=3).map(|i| Task { name: i.to_string(), run: Box::new(move || i) }).collect();
+        assert_eq!(3, table.row_count());
+        assert_eq!("\
++------+
+| name |
++======+
+| 1    |
++------+
+| 2    |
++------+
+| 3    |
++------+", table.to_string());
+    }
+    
     #[test]
     fn push_row() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -71,6 +105,7 @@ mod table_trait {
     #[test]
     fn insert_top() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -100,6 +135,7 @@ mod table_trait {
     #[test]
     fn insert() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -128,6 +164,7 @@ mod table_trait {
     #[test]
     fn get_column() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: u32,
             name: String
@@ -146,6 +183,7 @@ mod table_trait {
     #[test]
     fn get_column_sizes() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: u32,
             name: String
@@ -161,9 +199,28 @@ mod table_trait {
         assert_eq!(4, table.get_column_size(|row| row.id).unwrap());
     }
     
+    #[test]
+    fn get_column_sizes_unicode() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32,
+            name: String
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let vec: Vec<TableRow> = vec![TableRow{id: 1, name: String::from("坂本龍一")}, TableRow{id: 2, name: String::from("Bjo\u{308}rk")}];
+        let table = MyTable::from_vec(&vec);
+        
+        assert_eq!(8, table.get_column_size(|row| row.name.clone()).unwrap());
+    }
+    
     #[test]
     fn rm_row_at() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -178,6 +235,7 @@ mod table_trait {
         #[table(rows = TableRow)]
         struct MyTable {}
         
+        #[allow(clippy::needless_return, clippy::needless_bool)]
         impl PartialEq for MyTable {
             fn eq(&self, other: &Self) -> bool {
                 let results: Vec<bool> = self.rows.iter().enumerate().map(|(i, row)| {
@@ -207,15 +265,123 @@ mod table_trait {
     }
 }
 
+mod rows_path {
+    use simple_tables::macros::table;
+    use simple_tables::core::{IdTable, Table};
+    
+    mod models {
+        use simple_tables::macros::table_row;
+        
+        #[table_row]
+        #[derive(Debug, Clone)]
+        pub struct Band {
+            pub id: u32,
+            pub name: String,
+        }
+    }
+    
+    #[table(rows = models::Band)]
+    struct BandTable {}
+    
+    #[table(rows = self::models::Band, uid = "id", indexed)]
+    struct IndexedBandTable {}
+    
+    #[test]
+    fn path() {
+        let table = BandTable::from_vec(&vec![models::Band { id: 1, name: "Opeth".to_string() }]);
+        assert_eq!(1, table.row_count());
+        assert_eq!(2, table.column_count());
+    }
+    
+    #[test]
+    fn path_with_uid() {
+        let table = IndexedBandTable::from_vec(&vec![models::Band { id: 1, name: "Opeth".to_string() }]);
+        assert_eq!("Opeth", table.get_row(1).unwrap().name);
+    }
+}
+
+mod generics {
+    use std::fmt::Display;
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::core::{IdTable, Table, TableRow};
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct Measurement<T: Display> {
+        name: String,
+        value: T,
+    }
+    
+    #[table(rows = Measurement<f64>)]
+    struct Measurements {}
+    
+    #[table(rows = Measurement<T>, uid = "name")]
+    struct GenericMeasurements<T: Display + Clone> {}
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct Pair<K, V> where K: Display, V: Display {
+        key: K,
+        value: V,
+    }
+    
+    #[table(rows = Pair<K, u32>, uid = "key", indexed)]
+    struct Pairs<K> where K: Display + Clone + std::hash::Hash + Eq {}
+    
+    #[test]
+    fn generic_row() {
+        let table = Measurements::from_vec(&vec![
+            Measurement { name: "temperature".to_string(), value: 21.5 },
+            Measurement { name: "humidity".to_string(), value: 0.4 },
+        ]);
+        assert_eq!(vec!["name", "value"], Measurement::<f64>::get_fields());
+        assert_eq!("\
++-------------+-------+
+| name        | value |
++=============+=======+
+| temperature |  21.5 |
++-------------+-------+
+| humidity    |   0.4 |
++-------------+-------+", table.to_string());
+    }
+    
+    #[test]
+    fn generic_row_sort_and_import() {
+        let mut table = Measurements::from_csv_reader("name,value\na,2.5\nb,1\n".as_bytes()).unwrap();
+        table.sort_on("value").unwrap();
+        assert_eq!("b", table.get_rows()[0].name);
+    }
+    
+    #[test]
+    fn generic_table() {
+        let mut table: GenericMeasurements<u8> = GenericMeasurements::new();
+        table.push(Measurement { name: "count".to_string(), value: 3 });
+        assert_eq!(3, table.get_row("count".to_string()).unwrap().value);
+    }
+    
+    #[test]
+    fn where_clause() {
+        let mut table: Pairs<char> = Pairs::new();
+        table.push(Pair { key: 'a', value: 1 });
+        table.push(Pair { key: 'b', value: 2 });
+        assert_eq!(2, table.get_row('b').unwrap().value);
+        assert_eq!(Some(0), table.get_row_index('a'));
+    }
+}
+
 mod uid {
     use simple_tables::macros::table;
     use simple_tables::macros::table_row;
     use simple_tables::core::Table;
     use simple_tables_core::IdTable;
+    use simple_tables::error::TableErrorKind;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
     
     #[test]
     fn get_row() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -245,6 +411,7 @@ mod uid {
     fn get_row_mut() {
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -275,6 +442,7 @@ mod uid {
     fn get_row_mut_can_edit() {
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -315,6 +483,7 @@ mod uid {
     #[test]
     fn rm_row() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -353,6 +522,341 @@ mod uid {
         assert_eq!(rm_should_be, removed_row);
         assert_eq!(table_left, table)
     }
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    struct Band {
+        id: i32,
+        name: String,
+    }
+    
+    #[table(rows = Band)]
+    #[derive(PartialEq, Eq, Hash)]
+    struct BandTable {}
+    
+    impl IdTable<i32, Band> for BandTable {
+        fn get_id_from_row(row: &Band) -> i32 {
+            row.id
+        }
+    }
+    
+    fn band_table() -> BandTable {
+        BandTable::from_vec(&vec![
+            Band { id: 3, name: "Opeth".to_string() },
+            Band { id: 1, name: "Slipknot".to_string() },
+            Band { id: 2, name: "Gojira".to_string() },
+        ])
+    }
+    
+    fn ids<T: Table<Band>>(table: &T) -> Vec<i32> {
+        table.get_rows().iter().map(|row| row.id).collect()
+    }
+    
+    #[test]
+    fn sort() {
+        let mut table = band_table();
+        assert!(!table.is_sorted());
+        
+        table.sort();
+        assert!(table.is_sorted());
+        assert_eq!(vec![1, 2, 3], ids(&table));
+    }
+    
+    #[test]
+    fn new_table_is_sorted() {
+        assert!(BandTable::new().is_sorted());
+    }
+    
+    #[test]
+    fn sorted_search() {
+        let mut table = band_table();
+        table.sort();
+        
+        assert_eq!("Slipknot", table.sorted_search(1).unwrap().name);
+        assert_eq!("Gojira", table.sorted_search(2).unwrap().name);
+        assert_eq!("Opeth", table.sorted_search(3).unwrap().name);
+        assert!(table.sorted_search(4).is_none());
+    }
+    
+    #[test]
+    fn sorted_search_unsorted() {
+        let table = band_table();
+        
+        assert_eq!("Opeth", table.sorted_search(3).unwrap().name);
+        assert!(table.sorted_search(0).is_none());
+    }
+    
+    #[test]
+    fn push_invalidates_sort() {
+        let mut table = band_table();
+        table.sort();
+        table.push(Band { id: 0, name: "Metallica".to_string() });
+        assert!(!table.is_sorted());
+        assert_eq!("Metallica", table.sorted_search(0).unwrap().name);
+        
+        table.sort();
+        table.insert(1, Band { id: 5, name: "Mastodon".to_string() });
+        assert!(!table.is_sorted());
+        
+        table.sort();
+        table.get_row_mut(1).unwrap().id = 10;
+        assert!(!table.is_sorted());
+        assert_eq!("Slipknot", table.sorted_search(10).unwrap().name);
+    }
+    
+    #[test]
+    fn insert_sorted() {
+        let mut table = band_table();
+        table.sort();
+        table.insert_sorted(Band { id: 0, name: "Metallica".to_string() });
+        table.insert_sorted(Band { id: 2, name: "Mastodon".to_string() });
+        table.insert_sorted(Band { id: 5, name: "Tool".to_string() });
+        
+        assert!(table.is_sorted());
+        assert_eq!(vec![0, 1, 2, 2, 3, 5], ids(&table));
+        assert_eq!("Mastodon", table.get_rows()[3].name);
+        assert_eq!("Tool", table.sorted_search(5).unwrap().name);
+    }
+    
+    #[test]
+    fn sorted_tables_are_equal() {
+        fn hash<T: Hash>(table: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            table.hash(&mut hasher);
+            hasher.finish()
+        }
+        
+        assert_eq!(BandTable::new(), BandTable::from_rows(vec![]));
+        assert_eq!(hash(&BandTable::new()), hash(&BandTable::from_rows(vec![])));
+        
+        let mut table = band_table();
+        table.sort();
+        let other = BandTable::from_rows(table.get_rows().clone());
+        assert_eq!(table, other);
+        assert_eq!(hash(&table), hash(&other));
+        
+        let mut indexed = indexed_band_table();
+        assert_eq!("Opeth", indexed.get_row(3).unwrap().name);
+        indexed.sort();
+        let other = IndexedBandTable::from_rows(indexed.get_rows().clone());
+        assert_eq!(indexed, other);
+    }
+    
+    #[test]
+    fn sorted_search_duplicates() {
+        let mut table = band_table();
+        table.sort();
+        for name in ["Mastodon", "Tool", "Metallica"] {
+            table.insert_sorted(Band { id: 2, name: name.to_string() });
+        }
+        
+        assert_eq!(vec![1, 2, 2, 2, 2, 3], ids(&table));
+        assert_eq!("Gojira", table.sorted_search(2).unwrap().name);
+        assert_eq!(table.get_row(2), table.sorted_search(2));
+    }
+    
+    #[test]
+    fn uid_attribute() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct Album {
+            title: String,
+            year: u16,
+        }
+        
+        #[table(rows = Album, uid = "title")]
+        struct AlbumTable {}
+        
+        let mut table = AlbumTable::from_vec(&vec![
+            Album { title: "Blackwater Park".to_string(), year: 2001 },
+            Album { title: "Damnation".to_string(), year: 2003 },
+        ]);
+        
+        assert_eq!("title", AlbumTable::UID);
+        assert_eq!("Damnation", AlbumTable::get_id_from_row(&table.get_rows()[1]));
+        assert_eq!(2001, table.get_row("Blackwater Park".to_string()).unwrap().year);
+        
+        table.sort();
+        assert_eq!(2003, table.sorted_search("Damnation".to_string()).unwrap().year);
+    }
+    
+    #[table(rows = Band, uid = "id", indexed)]
+    #[derive(PartialEq)]
+    struct IndexedBandTable {}
+    
+    fn indexed_band_table() -> IndexedBandTable {
+        IndexedBandTable::from_vec(&vec![
+            Band { id: 3, name: "Opeth".to_string() },
+            Band { id: 1, name: "Slipknot".to_string() },
+            Band { id: 2, name: "Gojira".to_string() },
+        ])
+    }
+    
+    #[test]
+    fn indexed_get_row() {
+        let mut table = indexed_band_table();
+        
+        assert_eq!("Opeth", table.get_row(3).unwrap().name);
+        assert_eq!(Some(1), table.get_row_index(1));
+        assert!(table.get_row(4).is_none());
+        
+        table.get_row_mut(2).unwrap().name = "Mastodon".to_string();
+        assert_eq!("Mastodon", table.get_row(2).unwrap().name);
+    }
+    
+    #[test]
+    fn indexed_push_and_insert() {
+        let mut table = indexed_band_table();
+        assert_eq!("Gojira", table.get_row(2).unwrap().name);
+        
+        table.push(Band { id: 4, name: "Tool".to_string() });
+        assert_eq!(Some(3), table.get_row_index(4));
+        
+        table.insert_top(Band { id: 5, name: "Metallica".to_string() });
+        assert_eq!(Some(0), table.get_row_index(5));
+        assert_eq!(Some(4), table.get_row_index(4));
+        
+        table.insert(2, Band { id: 6, name: "Mastodon".to_string() });
+        assert_eq!(Some(2), table.get_row_index(6));
+        assert_eq!(Some(3), table.get_row_index(1));
+    }
+    
+    #[test]
+    fn indexed_remove() {
+        let mut table = indexed_band_table();
+        assert_eq!(Some(2), table.get_row_index(2));
+        
+        table.rm_row_at(0);
+        assert!(table.get_row(3).is_none());
+        assert_eq!(Some(1), table.get_row_index(2));
+        
+        assert_eq!("Slipknot", table.rm_row(1).unwrap().name);
+        assert!(table.get_row(1).is_none());
+        assert_eq!(Some(0), table.get_row_index(2));
+    }
+    
+    #[test]
+    fn indexed_duplicate_uids() {
+        let mut table = indexed_band_table();
+        assert_eq!(Some(0), table.get_row_index(3));
+        
+        table.insert(1, Band { id: 3, name: "Tool".to_string() });
+        table.insert_top(Band { id: 1, name: "Metallica".to_string() });
+        assert_eq!("Metallica", table.get_row(1).unwrap().name);
+        assert_eq!(Some(1), table.get_row_index(3));
+        
+        table.rm_row_at(1);
+        assert_eq!("Tool", table.get_row(3).unwrap().name);
+        assert_eq!(Some(1), table.get_row_index(3));
+        assert_eq!(Some(3), table.get_row_index(2));
+        
+        table.rm_row(1).unwrap();
+        assert_eq!("Slipknot", table.get_row(1).unwrap().name);
+        assert_eq!(Some(1), table.get_row_index(1));
+    }
+        
+    #[test]
+    fn indexed_insert_after_edit() {
+        let mut table = indexed_band_table();
+        
+        table.get_row_mut(1).unwrap().id = 10;
+        table.insert_top(Band { id: 4, name: "Tool".to_string() });
+        assert!(table.get_row(1).is_none());
+        assert_eq!(Some(2), table.get_row_index(10));
+        
+        table.get_row_mut(2).unwrap().id = 20;
+        table.rm_row_at(0);
+        assert!(table.get_row(2).is_none());
+        assert_eq!(Some(2), table.get_row_index(20));
+    }
+        
+    #[test]
+    fn indexed_edit_uid() {
+        let mut table = indexed_band_table();
+        
+        table.get_row_mut(1).unwrap().id = 10;
+        assert!(table.get_row(1).is_none());
+        assert_eq!("Slipknot", table.get_row(10).unwrap().name);
+        assert_eq!("Opeth", table.get_row(3).unwrap().name);
+        
+        table.get_row_mut(3).unwrap().id = 30;
+        assert_eq!("Slipknot", table.get_row(10).unwrap().name);
+        assert_eq!("Opeth", table.get_row(30).unwrap().name);
+        
+        table.get_rows_mut()[2].id = 20;
+        assert!(table.get_row(2).is_none());
+        assert_eq!("Gojira", table.get_row(20).unwrap().name);
+        
+        table.sort();
+        assert_eq!(Some(0), table.get_row_index(10));
+        assert_eq!(Some(2), table.get_row_index(30));
+    }
+    
+    #[test]
+    fn indexed_eq() {
+        let table = indexed_band_table();
+        table.get_row(1);
+        assert_eq!(indexed_band_table(), table);
+    }
+    
+    #[test]
+    fn try_push() {
+        let mut table = indexed_band_table();
+        
+        assert!(table.try_push(Band { id: 4, name: "Tool".to_string() }).is_ok());
+        let err = table.try_push(Band { id: 1, name: "Metallica".to_string() }).unwrap_err();
+        assert!(matches!(err.kind, TableErrorKind::DuplicateUid));
+        assert_eq!(4, table.row_count());
+        assert_eq!("Slipknot", table.get_row(1).unwrap().name);
+    }
+    
+    #[test]
+    fn try_insert() {
+        let mut table = band_table();
+        
+        assert!(table.try_insert(0, Band { id: 4, name: "Tool".to_string() }).is_ok());
+        assert_eq!(Some(0), table.get_row_index(4));
+        let err = table.try_insert(0, Band { id: 3, name: "Metallica".to_string() }).unwrap_err();
+        assert!(matches!(err.kind, TableErrorKind::DuplicateUid));
+        assert_eq!(vec![4, 3, 1, 2], ids(&table));
+    }
+    
+    #[test]
+    fn upsert() {
+        let mut table = indexed_band_table();
+        
+        let replaced = table.upsert(Band { id: 1, name: "Metallica".to_string() });
+        assert_eq!("Slipknot", replaced.unwrap().name);
+        assert_eq!("Metallica", table.get_row(1).unwrap().name);
+        assert_eq!(Some(1), table.get_row_index(1));
+        
+        assert!(table.upsert(Band { id: 4, name: "Tool".to_string() }).is_none());
+        assert_eq!(vec![3, 1, 2, 4], ids(&table));
+    }
+    
+    #[test]
+    fn upsert_keeps_sort() {
+        let mut table = band_table();
+        table.sort();
+        
+        table.upsert(Band { id: 2, name: "Mastodon".to_string() });
+        assert!(table.is_sorted());
+        assert_eq!("Mastodon", table.sorted_search(2).unwrap().name);
+        
+        table.upsert(Band { id: 0, name: "Metallica".to_string() });
+        assert!(!table.is_sorted());
+    }
+    
+    #[test]
+    fn rm_row_keeps_sort() {
+        let mut table = band_table();
+        table.sort();
+        table.rm_row_at(0);
+        table.rm_row(3).unwrap();
+        
+        assert!(table.is_sorted());
+        assert_eq!(vec![2], ids(&table));
+    }
 }
 
 mod to_string {
@@ -363,6 +867,7 @@ mod to_string {
     #[test]
     fn to_string() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: u32,
             name: String
@@ -377,11 +882,11 @@ mod to_string {
         // table.to_string();
         let ascii_table = "\
 +------+------+
-| id   | name |
+|   id | name |
 +======+======+
 | 1000 | Abc  |
 +------+------+
-| 2    | Bd   |
+|    2 | Bd   |
 +------+------+";
         assert_eq!(ascii_table, table.to_string())
     }
@@ -389,6 +894,7 @@ mod to_string {
     #[test]
     fn to_string2() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: u64,
             name: String,
@@ -408,18 +914,156 @@ mod to_string {
         // table.to_string();
         let ascii_table = "\
 +-------------------+---------------------+-------------------+
-| id                | name                | email             |
+|                id | name                | email             |
 +===================+=====================+===================+
-| 425549252244      | Nothing But Thieves | info@nbt.com      |
+|      425549252244 | Nothing But Thieves | info@nbt.com      |
 +-------------------+---------------------+-------------------+
 | 34459529244554252 | David Bowie         | info@bowie.com    |
 +-------------------+---------------------+-------------------+
-| 45052024          | Slipknot            | info@slipknot.com |
+|          45052024 | Slipknot            | info@slipknot.com |
 +-------------------+---------------------+-------------------+";
         assert_eq!(ascii_table, table.to_string())
     }
     
-    // TODO: test for Debug implementation
+    #[test]
+    fn render_with_styles() {
+        use simple_tables::style::TableStyle;
+        
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32,
+            name: String
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let vec: Vec<TableRow> = vec![TableRow{id: 1000, name: String::from("Abc")}, TableRow{id: 2, name: String::from("Bd")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        assert_eq!(table.to_string(), table.render_with(&TableStyle::ASCII));
+        assert_eq!("\
+┌──────┬──────┐
+│   id │ name │
+├──────┼──────┤
+│ 1000 │ Abc  │
+├──────┼──────┤
+│    2 │ Bd   │
+└──────┴──────┘", table.render_with(&TableStyle::UNICODE));
+        assert_eq!("\
+╔══════╦══════╗
+║   id ║ name ║
+╠══════╬══════╣
+║ 1000 ║ Abc  ║
+╠══════╬══════╣
+║    2 ║ Bd   ║
+╚══════╩══════╝", table.render_with(&TableStyle::DOUBLE));
+        assert_eq!("  id | name\n-----+-----\n1000 | Abc\n   2 | Bd", table.render_with(&TableStyle::PSQL));
+        assert_eq!("  id  name\n1000  Abc\n   2  Bd", table.render_with(&TableStyle::BORDERLESS));
+    }
+    
+    #[test]
+    fn debug() {
+        #[table_row]
+        #[derive(Debug, Clone)]
+        struct TableRow {
+            id: u32,
+            name: String
+        }
+        
+        #[table(rows = TableRow)]
+        struct MyTable {}
+        
+        let vec: Vec<TableRow> = vec![TableRow{id: 1000, name: String::from("Abc")}, TableRow{id: 2, name: String::from("Bd")}];
+        let table: MyTable = MyTable::from_vec(&vec);
+        
+        assert_eq!(table.to_string(), format!("{:?}", table));
+    }
 }
 
-// TODO: Implement debug!
\ No newline at end of file
+
+mod sort {
+    use simple_tables::macros::table;
+    use simple_tables::macros::table_row;
+    use simple_tables::core::Table;
+    use simple_tables::error::TableErrorKind;
+    use simple_tables::sort::Order;
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct TableRow {
+        id: u32,
+        name: String,
+        country: String
+    }
+    
+    #[table(rows = TableRow)]
+    struct MyTable {}
+    
+    fn table() -> MyTable {
+        MyTable::from_vec(&vec![
+            TableRow { id: 3, name: "Opeth".to_string(), country: "Sweden".to_string() },
+            TableRow { id: 10, name: "Pink Floyd".to_string(), country: "England".to_string() },
+            TableRow { id: 1, name: "ABBA".to_string(), country: "Sweden".to_string() },
+            TableRow { id: 2, name: "David Bowie".to_string(), country: "England".to_string() }
+        ])
+    }
+    
+    #[test]
+    fn sort_on() {
+        let mut table = table();
+        table.sort_on("id").unwrap();
+        assert_eq!(vec![1, 2, 3, 10], table.get_column(|row| row.id));
+        
+        table.sort_on("name").unwrap();
+        assert_eq!(vec![1, 2, 3, 10], table.get_column(|row| row.id));
+    }
+    
+    #[test]
+    fn sort_on_columns() {
+        let mut table = table();
+        table.sort_on_columns(&[("country", Order::Ascending), ("name", Order::Ascending)]).unwrap();
+        assert_eq!(vec![2, 10, 1, 3], table.get_column(|row| row.id));
+        
+        table.sort_on_columns(&[("country", Order::Descending), ("id", Order::Descending)]).unwrap();
+        assert_eq!(vec![3, 1, 10, 2], table.get_column(|row| row.id));
+    }
+    
+    #[test]
+    fn stable() {
+        let mut table = table();
+        table.sort_on("country").unwrap();
+        assert_eq!(vec![10, 2, 3, 1], table.get_column(|row| row.id));
+    }
+    
+    #[test]
+    fn sort_by_key() {
+        let mut table = table();
+        table.sort_by_key(|row| std::cmp::Reverse(row.name.len()));
+        assert_eq!(vec![2, 10, 3, 1], table.get_column(|row| row.id));
+        
+        table.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(vec![1, 2, 3, 10], table.get_column(|row| row.id));
+    }
+    
+    #[test]
+    fn get_sorted() {
+        let table = table();
+        let sorted = table.get_sorted("id").unwrap();
+        assert_eq!(vec![1, 2, 3, 10], sorted.iter().map(|row| row.id).collect::<Vec<u32>>());
+        assert_eq!(vec![3, 10, 1, 2], table.get_column(|row| row.id));
+        
+        let sorted = table.get_sorted_on_columns(&[("id", Order::Descending)]).unwrap();
+        assert_eq!(vec![10, 3, 2, 1], sorted.iter().map(|row| row.id).collect::<Vec<u32>>());
+    }
+    
+    #[test]
+    fn unknown_column() {
+        let mut table = table();
+        let error = table.sort_on("year").unwrap_err();
+        assert!(matches!(error.kind, TableErrorKind::UnknownColumn));
+        assert_eq!("There is no column `year`", error.to_string());
+        assert_eq!(vec![3, 10, 1, 2], table.get_column(|row| row.id));
+    }
+}
@@ -4,6 +4,7 @@ mod field_names {
         use simple_tables::macros::table_row;
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct Row {
             id: i32,
             name: String
@@ -16,9 +17,11 @@ mod field_names {
     
     #[test]
     fn fields() {
+        use simple_tables::TableRow;
         use simple_tables::macros::table_row;
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct Row {
             id: i32,
             name: String,
@@ -32,9 +35,11 @@ mod field_names {
     
     #[test]
     fn field_count() {
+        use simple_tables::TableRow;
         use simple_tables::macros::table_row;
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct Row {
             id: i32,
             name: String,
@@ -50,9 +55,11 @@ mod field_names {
 mod field_types {
     #[test]
     fn field_types() {
+        use simple_tables::TableRow;
         use simple_tables::macros::table_row;
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct Row {
             id: u32,
             name: String
@@ -67,6 +74,7 @@ mod field_types {
         use simple_tables::macros::table_row;
         
         #[table_row]
+        #[derive(Debug, Clone)]
         struct Row {
             id: u32,
             name: String,
@@ -83,6 +91,7 @@ mod other_methods {
     #[test]
     fn to_string() {
         #[table_row]
+        #[derive(Debug, Clone)]
         struct TableRow {
             id: i32,
             name: String,
@@ -92,4 +101,489 @@ mod other_methods {
         let row = TableRow { id: 1, name: String::from("My Name"), char: 'e' };
         assert_eq!(vec!["1".to_string(), "My Name".to_string(), 'e'.to_string()], row.get_field_str());
     }
-}
\ No newline at end of file
+}
+
+mod column_attributes {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::{Table, TableRow};
+    use simple_tables::render::Alignment;
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct Product {
+        #[column(rename = "Product name")]
+        name: String,
+        #[column(format = "{:.2}", align = "center")]
+        price: f64,
+        #[column(skip)]
+        internal_id: u64,
+        #[column(rename = "In stock", default)]
+        stock: u32,
+    }
+    
+    #[table(rows = Product)]
+    struct ProductTable {}
+    
+    fn product() -> Product {
+        Product { name: "Guitar".to_string(), price: 499.5, internal_id: 42, stock: 3 }
+    }
+    
+    #[test]
+    fn rename() {
+        assert_eq!(vec!["Product name", "price", "In stock"], Product::get_fields());
+        assert_eq!(["Product name", "price", "In stock"], Product::FIELDS);
+    }
+    
+    #[test]
+    fn skip() {
+        assert_eq!(3, Product::field_count());
+        assert_eq!(vec!["String", "f64", "u32"], Product::get_field_types());
+        assert_eq!(vec![Alignment::Left, Alignment::Center, Alignment::Right], Product::get_field_alignments());
+        assert_eq!(42, product().internal_id);
+    }
+    
+    #[test]
+    fn format() {
+        assert_eq!(vec!["Guitar".to_string(), "499.50".to_string(), "3".to_string()], product().get_field_str());
+    }
+    
+    #[test]
+    fn render() {
+        let table = ProductTable::from_vec(&vec![product()]);
+        assert_eq!("\
++--------------+--------+----------+
+| Product name | price  | In stock |
++==============+========+==========+
+| Guitar       | 499.50 |        3 |
++--------------+--------+----------+", table.to_string());
+        assert_eq!("Product name,price,In stock\r\nGuitar,499.50,3\r\n", table.to_csv());
+    }
+    
+    #[test]
+    fn import() {
+        let csv = "Product name,price\nGuitar,499.5\n";
+        let table = ProductTable::from_csv_reader(csv.as_bytes()).unwrap();
+        let row = &table.get_rows()[0];
+        assert_eq!("Guitar", row.name);
+        assert_eq!(0, row.internal_id);
+        assert_eq!(0, row.stock);
+        
+        let csv = "Product name,price,In stock\nGuitar,499.5,\nBass,399,2\n";
+        let table = ProductTable::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(0, table.get_rows()[0].stock);
+        assert_eq!(2, table.get_rows()[1].stock);
+        
+        // Columns without `default` are still required
+        assert!(ProductTable::from_csv_reader("Product name\nGuitar\n".as_bytes()).is_err());
+        assert!(ProductTable::from_csv_reader("Product name,price\nGuitar,\n".as_bytes()).is_err());
+    }
+    
+    #[test]
+    fn sort_on_renamed_column() {
+        let mut table = ProductTable::from_vec(&vec![product(), Product { name: "Bass".to_string(), price: 399.0, internal_id: 1, stock: 1 }]);
+        table.sort_on("Product name").unwrap();
+        assert_eq!("Bass", table.get_rows()[0].name);
+        assert!(table.sort_on("internal_id").is_err());
+    }
+}
+
+mod hygiene {
+    // The generated code must not depend on the names in scope, nor add any
+    #![allow(dead_code)]
+    use simple_tables::macros::{table_row, table};
+    
+    struct Vec;
+    struct String;
+    type Result = ();
+    type Option = ();
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct First {
+        id: u32,
+    }
+    
+    #[table_row]
+    #[derive(Debug, Clone)]
+    struct Second {
+        id: u32,
+    }
+    
+    #[table(rows = First, uid = "id", indexed)]
+    struct FirstTable {}
+    
+    #[table(rows = Second)]
+    struct SecondTable {}
+    
+    #[test]
+    fn multiple_rows_in_one_module() {
+        use simple_tables::{IdTable, Table};
+        let table = FirstTable::from_vec(&vec![First { id: 1 }, First { id: 2 }]);
+        assert_eq!(1, table.get_row_index(2).unwrap());
+        assert_eq!(1, SecondTable::from_vec(&vec![Second { id: 1 }]).get_rows().len());
+    }
+}
+
+mod tuple_structs {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::{IdTable, Table, TableRow};
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Pair(u32, String);
+    
+    #[table_row(columns = ["x", "y"])]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point(i32, #[column(align = "left")] i32);
+    
+    #[table(rows = Point, uid = "x")]
+    struct Points {}
+    
+    #[test]
+    fn positional_names() {
+        assert_eq!(vec!["0", "1"], Pair::get_fields());
+        assert_eq!(vec!["u32", "String"], Pair::get_field_types());
+        assert_eq!(vec!["1", "Opeth"], Pair(1, "Opeth".to_string()).get_field_str());
+    }
+    
+    #[test]
+    fn column_names() {
+        let mut table = Points::from_rows(vec![Point(3, 4), Point(1, 20)]);
+        assert_eq!(vec!["x", "y"], Point::get_fields());
+        assert_eq!(Some(&Point(1, 20)), table.get_row(1));
+        
+        table.sort_on("y").unwrap();
+        assert_eq!("\
++---+----+
+| x | y  |
++===+====+
+| 3 | 4  |
++---+----+
+| 1 | 20 |
++---+----+", table.to_string());
+    }
+    
+    #[test]
+    fn import() {
+        let table = Points::from_csv_reader("y,x\n2,1\n".as_bytes()).unwrap();
+        assert_eq!(&vec![Point(1, 2)], table.get_rows());
+    }
+}
+
+mod enums {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::{IdTable, Table, TableRow};
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Event {
+        Login { id: u32, user: String },
+        Purchase { id: u32, user: String, #[column(format = "{:.2}")] amount: f64 },
+        Error { id: u32, message: String, #[column(skip)] retries: u8 },
+        Restart,
+    }
+    
+    #[table(rows = Event)]
+    struct Log {}
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    enum Entry {
+        Deposit { id: u32, amount: u32 },
+        Withdrawal { id: u32, amount: u32 },
+    }
+    
+    #[table(rows = Entry, uid = "id")]
+    struct Ledger {}
+    
+    fn log() -> Log {
+        Log::from_rows(vec![
+            Event::Login { id: 1, user: "david".to_string() },
+            Event::Purchase { id: 2, user: "david".to_string(), amount: 9.5 },
+            Event::Error { id: 3, message: "Out of stock".to_string(), retries: 2 },
+            Event::Restart,
+        ])
+    }
+    
+    #[test]
+    fn columns() {
+        assert_eq!(vec!["kind", "id", "user", "amount", "message"], Event::get_fields());
+        assert_eq!(vec!["&str", "u32", "String", "f64", "String"], Event::get_field_types());
+        assert_eq!(vec!["Purchase", "2", "david", "9.50", ""], Event::Purchase { id: 2, user: "david".to_string(), amount: 9.5 }.get_field_str());
+        assert_eq!(vec!["Restart", "", "", "", ""], Event::Restart.get_field_str());
+    }
+    
+    #[test]
+    fn to_string() {
+        assert_eq!("\
++----------+----+-------+--------+--------------+
+| kind     | id | user  | amount | message      |
++==========+====+=======+========+==============+
+| Login    |  1 | david |        |              |
++----------+----+-------+--------+--------------+
+| Purchase |  2 | david |   9.50 |              |
++----------+----+-------+--------+--------------+
+| Error    |  3 |       |        | Out of stock |
++----------+----+-------+--------+--------------+
+| Restart  |    |       |        |              |
++----------+----+-------+--------+--------------+", log().to_string());
+    }
+    
+    #[test]
+    fn csv_round_trip() {
+        let csv = log().to_csv();
+        assert_eq!("kind,id,user,amount,message\r\nLogin,1,david,,\r\nPurchase,2,david,9.50,\r\nError,3,,,Out of stock\r\nRestart,,,,\r\n", csv);
+        
+        let table = Log::from_csv_reader(csv.as_bytes()).unwrap();
+        assert_eq!(Event::Error { id: 3, message: "Out of stock".to_string(), retries: 0 }, table.get_rows()[2]);
+        assert_eq!(Event::Restart, table.get_rows()[3]);
+        
+        // Columns that only some variants have can be left out
+        let table = Log::from_csv_reader("kind,id\nRestart,\n".as_bytes()).unwrap();
+        assert_eq!(&vec![Event::Restart], table.get_rows());
+        
+        let error = Log::from_csv_reader("kind\nShutdown\n".as_bytes()).unwrap_err();
+        assert_eq!("Could not parse column `kind` of row 0 (line 2): Unknown kind `Shutdown`, expected one of `Login`, `Purchase`, `Error`, `Restart`", error.to_string());
+    }
+    
+    #[test]
+    fn sort() {
+        let mut table = log();
+        table.sort_on("kind").unwrap();
+        assert_eq!(vec!["Error", "Login", "Purchase", "Restart"], table.get_column(|row| row.get_field_str()[0].clone()));
+        
+        // Rows without the column come first
+        table.sort_on("user").unwrap();
+        assert_eq!(vec!["Error", "Restart", "Login", "Purchase"], table.get_column(|row| row.get_field_str()[0].clone()));
+    }
+    
+    #[test]
+    fn uid() {
+        let table = Ledger::from_rows(vec![Entry::Deposit { id: 1, amount: 10 }, Entry::Withdrawal { id: 2, amount: 5 }]);
+        assert_eq!(Some(&Entry::Withdrawal { id: 2, amount: 5 }), table.get_row(2));
+    }
+    
+    #[test]
+    fn kind_field() {
+        #[table_row]
+        enum Animal {
+            Dog { #[column(rename = "breed")] kind: String },
+            Cat { name: String, #[column(skip)] kind: u8 },
+        }
+        
+        assert_eq!(vec!["kind", "breed", "name"], Animal::get_fields());
+        assert_eq!(vec!["Dog", "Beagle", ""], Animal::Dog { kind: "Beagle".to_string() }.get_field_str());
+        let cat = Animal::Cat { name: "Tom".to_string(), kind: 1 };
+        assert_eq!(vec!["Cat", "", "Tom"], cat.get_field_str());
+        assert!(matches!(cat, Animal::Cat { kind: 1, .. }));
+    }
+}
+
+mod flatten {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::{Table, TableRow};
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Address {
+        street: String,
+        #[column(default)]
+        number: u32,
+    }
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Person {
+        name: String,
+        #[column(flatten)]
+        address: Address,
+        #[column(flatten, rename = "work")]
+        work_address: Address,
+        age: u8,
+    }
+    
+    #[table(rows = Person, uid = "name")]
+    struct People {}
+    
+    fn person() -> Person {
+        Person {
+            name: "David".to_string(),
+            address: Address { street: "Main Street".to_string(), number: 1 },
+            work_address: Address { street: "Abbey Road".to_string(), number: 3 },
+            age: 30,
+        }
+    }
+    
+    #[test]
+    fn columns() {
+        assert_eq!(vec!["name", "address.street", "address.number", "work.street", "work.number", "age"], Person::get_fields());
+        assert_eq!(vec!["String", "String", "u32", "String", "u32", "u8"], Person::get_field_types());
+        assert_eq!(vec!["David", "Main Street", "1", "Abbey Road", "3", "30"], person().get_field_str());
+        assert_eq!(6, Person::field_count());
+    }
+    
+    #[test]
+    fn to_string() {
+        let table = People::from_vec(&vec![person()]);
+        assert_eq!("\
++-------+----------------+----------------+-------------+-------------+-----+
+| name  | address.street | address.number | work.street | work.number | age |
++=======+================+================+=============+=============+=====+
+| David | Main Street    |              1 | Abbey Road  |           3 |  30 |
++-------+----------------+----------------+-------------+-------------+-----+", table.to_string());
+    }
+    
+    #[test]
+    fn csv_round_trip() {
+        let csv = People::from_vec(&vec![person()]).to_csv();
+        assert_eq!("name,address.street,address.number,work.street,work.number,age\r\nDavid,Main Street,1,Abbey Road,3,30\r\n", csv);
+        assert_eq!(&vec![person()], People::from_csv_reader(csv.as_bytes()).unwrap().get_rows());
+        
+        // Optional columns of the nested row are optional here as well
+        let table = People::from_csv_reader("name,address.street,work.street,age\nDavid,Main Street,Abbey Road,30\n".as_bytes()).unwrap();
+        assert_eq!(0, table.get_rows()[0].work_address.number);
+        
+        let error = People::from_csv_reader("name,address.street,address.number,work.street,age\nDavid,Main Street,one,Abbey Road,30\n".as_bytes()).unwrap_err();
+        assert_eq!("Could not parse column `address.number` of row 0 (line 2): invalid digit found in string", error.to_string());
+    }
+    
+    #[test]
+    fn sort_on_nested_column() {
+        let mut other = person();
+        other.name = "Roger".to_string();
+        other.work_address.street = "Abbey Lane".to_string();
+        let mut table = People::from_vec(&vec![person(), other]);
+        table.sort_on("work.street").unwrap();
+        assert_eq!("Roger", table.get_rows()[0].name);
+        assert!(table.sort_on("work.city").is_err());
+    }
+}
+
+mod formatting {
+    use simple_tables::macros::{table_row, table};
+    use simple_tables::{Table, TableRow};
+    use simple_tables::render::Alignment;
+    
+    fn yes_no(value: &bool) -> String {
+        if *value { "yes" } else { "no" }.to_string()
+    }
+    
+    fn parse_yes_no(value: &str) -> Result<bool, String> {
+        match value {
+            "yes" => Ok(true),
+            "no" => Ok(false),
+            _ => Err(format!("expected `yes` or `no`, found `{}`", value))
+        }
+    }
+    
+    #[table_row]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Album {
+        title: String,
+        year: Option<u16>,
+        #[column(null = "unknown", format = "{:.1}")]
+        rating: Option<f32>,
+        #[column(debug)]
+        tags: Vec<&'static str>,
+        #[column(with = yes_no)]
+        released: bool,
+    }
+    
+    #[table(rows = Album)]
+    struct Albums {}
+    
+    #[table_row(null = "-")]
+    #[derive(Debug, Clone, PartialEq)]
+    struct Track {
+        title: String,
+        #[column(with = self::yes_no, parse_with = self::parse_yes_no)]
+        single: bool,
+        length: Option<u32>,
+    }
+    
+    #[table(rows = Track)]
+    struct Tracks {}
+    
+    fn albums() -> Albums {
+        Albums::from_rows(vec![
+            Album { title: "Blackwater Park".to_string(), year: Some(2001), rating: Some(4.25), tags: vec!["prog", "death"], released: true },
+            Album { title: "Untitled".to_string(), year: None, rating: None, tags: vec![], released: false },
+        ])
+    }
+    
+    #[test]
+    fn field_str() {
+        let rows = albums();
+        assert_eq!(vec!["Blackwater Park", "2001", "4.2", "[\"prog\", \"death\"]", "yes"], rows.get_rows()[0].get_field_str());
+        assert_eq!(vec!["Untitled", "", "unknown", "[]", "no"], rows.get_rows()[1].get_field_str());
+        assert_eq!(vec!["Intro", "no", "-"], Track { title: "Intro".to_string(), single: false, length: None }.get_field_str());
+    }
+    
+    #[test]
+    fn options_are_aligned_like_their_value() {
+        assert_eq!(vec![Alignment::Left, Alignment::Right, Alignment::Right, Alignment::Left, Alignment::Left], Album::get_field_alignments());
+    }
+    
+    #[test]
+    fn import_options() {
+        let table = Tracks::from_csv_reader("title,single,length\nIntro,no,-\nOutro,yes,\nSong,no,240\n".as_bytes()).unwrap();
+        assert_eq!(vec![None, None, Some(240)], table.get_column(|row| row.length));
+        
+        // Columns of `Option`s can be missing
+        let table = Tracks::from_csv_reader("title,single\nIntro,no\n".as_bytes()).unwrap();
+        assert_eq!(None, table.get_rows()[0].length);
+        
+        let error = Tracks::from_csv_reader("title,single,length\nIntro,no,long\n".as_bytes()).unwrap_err();
+        assert_eq!("Could not parse column `length` of row 0 (line 2): invalid digit found in string", error.to_string());
+    }
+    
+    #[test]
+    fn parse_with() {
+        let table = Tracks::from_rows(vec![
+            Track { title: "Intro".to_string(), single: false, length: None },
+            Track { title: "Song".to_string(), single: true, length: Some(240) },
+        ]);
+        let csv = table.to_csv();
+        assert_eq!("title,single,length\r\nIntro,no,-\r\nSong,yes,240\r\n", csv);
+        assert_eq!(table.get_rows(), Tracks::from_csv_reader(csv.as_bytes()).unwrap().get_rows());
+        
+        let error = Tracks::from_csv_reader("title,single\nIntro,false\n".as_bytes()).unwrap_err();
+        assert_eq!("Could not parse column `single` of row 0 (line 2): expected `yes` or `no`, found `false`", error.to_string());
+    }
+    
+    #[test]
+    fn sort_options() {
+        let mut table = albums();
+        table.sort_on("year").unwrap();
+        assert_eq!("Untitled", table.get_rows()[0].title);
+    }
+    
+    #[test]
+    fn enum_options() {
+        #[table_row(null = "n/a")]
+        enum Event {
+            Login { user: Option<String> },
+            Restart,
+        }
+        
+        assert_eq!(vec!["Login", "n/a"], Event::Login { user: None }.get_field_str());
+        assert_eq!(vec!["Restart", ""], Event::Restart.get_field_str());
+    }
+    
+    #[test]
+    fn enum_parse_with() {
+        #[table_row]
+        #[derive(Debug, Clone, PartialEq)]
+        enum Event {
+            Login { user: String, #[column(with = yes_no, parse_with = parse_yes_no)] admin: bool },
+            Restart,
+        }
+        
+        #[table(rows = Event)]
+        struct Log {}
+        
+        let table = Log::from_csv_reader("kind,user,admin\nLogin,david,yes\nRestart,,\n".as_bytes()).unwrap();
+        assert_eq!(&vec![Event::Login { user: "david".to_string(), admin: true }, Event::Restart], table.get_rows());
+        assert_eq!(vec!["Login", "david", "yes"], table.get_rows()[0].get_field_str());
+    }
+}
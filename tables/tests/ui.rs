@@ -0,0 +1,15 @@
+//! Checks the compile errors of the macros. Run with `TRYBUILD=overwrite` to update the expected
+//! output after changing an error message.
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
+
+#[test]
+#[cfg(not(feature = "serde"))]
+fn ui_no_serde() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/no_serde/*.rs");
+}
@@ -0,0 +1,15 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct Address {
+    street: String,
+}
+
+#[table_row]
+struct Person {
+    name: String,
+    #[column(flatten, format = "{:?}")]
+    address: Address,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct Row {
+    #[column(format = "{:?}", debug)]
+    tags: Vec<String>,
+}
+
+fn main() {}
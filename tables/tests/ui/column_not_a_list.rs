@@ -0,0 +1,10 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct MyTableRow {
+    #[column = "right"]
+    id: u32,
+    name: String,
+}
+
+fn main() {}
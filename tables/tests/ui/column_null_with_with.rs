@@ -0,0 +1,14 @@
+use simple_tables::macros::table_row;
+
+fn score(score: &Option<u32>) -> String {
+    score.map(|score| score.to_string()).unwrap_or_default()
+}
+
+#[table_row]
+struct MyTableRow {
+    id: u32,
+    #[column(with = score, null = "-")]
+    score: Option<u32>,
+}
+
+fn main() {}
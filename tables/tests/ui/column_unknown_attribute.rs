@@ -0,0 +1,10 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct MyTableRow {
+    #[column(width = 10)]
+    id: u32,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use simple_tables::macros::{table_row, table};
+use simple_tables::Table;
+
+fn yes_no(value: &bool) -> String {
+    if *value { "yes" } else { "no" }.to_string()
+}
+
+#[table_row]
+struct MyTableRow {
+    id: u32,
+    #[column(with = yes_no)]
+    active: bool,
+}
+
+#[table(rows = MyTableRow)]
+struct MyTable {}
+
+fn main() {
+    let _ = MyTable::from_csv_reader("id,active\n1,yes\n".as_bytes());
+}
@@ -0,0 +1,12 @@
+use simple_tables::macros::{table, table_row};
+
+#[table_row]
+struct MyTableRow {
+    id: u32,
+    name: String,
+}
+
+#[table(rows = MyTableRow, uid = "id", uid = "name")]
+struct MyTable {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use simple_tables::macros::{table, table_row};
+
+#[table_row]
+struct MyTableRow {
+    id: u32,
+    name: String,
+}
+
+#[table(rows = MyTableRow, indexed)]
+struct MyTable {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use simple_tables::macros::table;
+
+#[table(uid = "id")]
+struct MyTable {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use simple_tables::macros::table_row;
+
+#[table_row(columns = ["x", "y", "z"])]
+struct Point(f64, f64);
+
+fn main() {}
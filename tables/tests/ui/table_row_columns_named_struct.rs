@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row(columns = ["x", "y"])]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn main() {}
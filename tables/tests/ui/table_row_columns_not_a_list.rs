@@ -0,0 +1,6 @@
+use simple_tables::macros::table_row;
+
+#[table_row(columns = "x")]
+struct Point(f64);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct MyTableRow {
+    id: u32,
+    #[column(rename = "id")]
+    name: String,
+}
+
+fn main() {}
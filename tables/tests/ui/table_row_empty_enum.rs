@@ -0,0 +1,6 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+enum Event {}
+
+fn main() {}
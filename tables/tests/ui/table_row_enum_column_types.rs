@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+enum Event {
+    Login { id: u32 },
+    Error { id: String },
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+enum Event {
+    Login { id: u32, user: String },
+    Error { id: u32, #[column(rename = "id")] code: u32 },
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[table_row]
+enum Event {
+    Login {
+        #[column(flatten)]
+        user: User,
+    },
+    Logout,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+enum Event {
+    Login {
+        user: String,
+        #[column(rename = "kind")]
+        method: String,
+    },
+    Logout,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+enum Event {
+    Login { user: String, kind: String },
+    Logout,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use simple_tables::macros::table_row;
+
+#[table_row]
+struct Address {
+    street: String,
+    city: String,
+}
+
+#[table_row]
+struct MyTableRow {
+    #[column(rename = "address.city")]
+    city: String,
+    #[column(flatten)]
+    address: Address,
+}
+
+fn main() {}
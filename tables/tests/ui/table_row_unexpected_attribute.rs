@@ -0,0 +1,9 @@
+use simple_tables::macros::table_row;
+
+#[table_row(sorted)]
+struct MyTableRow {
+    id: u32,
+    name: String,
+}
+
+fn main() {}